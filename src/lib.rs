@@ -4,10 +4,14 @@ pub mod server {
     pub mod config;
     pub mod connection;
     pub mod error;
+    pub mod pipeline_store;
     pub mod process;
+    pub mod read_batch;
+    pub mod role_config;
     pub mod service;
     pub mod subscriber;
     pub mod subscribers;
+    pub mod write_errors;
 }
 pub mod target {
     pub mod driver;
@@ -16,6 +20,7 @@ pub mod target {
     pub mod program {
         pub mod action;
         pub mod actions;
+        pub mod counter_array;
         pub mod default_entry;
         pub mod field;
         pub mod header;
@@ -24,14 +29,17 @@ pub mod target {
         pub mod match_field_target;
         pub mod match_fields;
         pub mod match_type;
+        pub mod meter_array;
         pub mod param;
         pub mod pipeline;
         pub mod program;
+        pub mod register_array;
         pub mod runtime_data;
         pub mod source_info;
         pub mod table;
         pub use action::Action;
         pub use actions::Actions;
+        pub use counter_array::CounterArray;
         pub use default_entry::DefaultEntry;
         pub use field::Field;
         pub use header::Header;
@@ -40,9 +48,11 @@ pub mod target {
         pub use match_field_target::MatchFieldTarget;
         pub use match_fields::MatchFields;
         pub use match_type::MatchType;
+        pub use meter_array::MeterArray;
         pub use param::Param;
         pub use pipeline::Pipeline;
         pub use program::Program;
+        pub use register_array::RegisterArray;
         pub use runtime_data::RuntimeData;
         pub use source_info::SourceInfo;
         pub use table::Table;
@@ -52,6 +62,8 @@ pub mod target {
         pub mod action;
         pub mod attribute;
         pub mod cam_config;
+        pub mod controller_header;
+        pub mod controller_header_field;
         pub mod counter_config;
         pub mod global_action;
         pub mod global_actions;
@@ -66,12 +78,15 @@ pub mod target {
         pub mod target_counter_config;
         pub mod target_ctrl_config;
         pub mod target_interrupt_config;
+        pub mod target_meter_config;
         pub mod target_register_config;
         pub mod target_table_config;
         pub mod target_table_configs;
         pub use action::ActionSchema;
         pub use attribute::AttributeSchema;
         pub use cam_config::CamConfigSchema;
+        pub use controller_header::ControllerHeaderSchema;
+        pub use controller_header_field::ControllerHeaderFieldSchema;
         pub use counter_config::CounterConfigSchema;
         pub use global_action::GlobalActionSchema;
         pub use global_actions::GlobalActionsSchema;
@@ -86,22 +101,38 @@ pub mod target {
         pub use target_counter_config::TargetCounterConfigSchema;
         pub use target_ctrl_config::TargetCtrlConfigSchema;
         pub use target_interrupt_config::TargetInterruptConfigSchema;
+        pub use target_meter_config::TargetMeterConfigSchema;
         pub use target_register_config::TargetRegisterConfigSchema;
         pub use target_table_config::TargetTableConfigSchema;
         pub use target_table_configs::TargetTableConfigsSchema;
     }
     pub mod models {
+        pub mod counter;
         pub mod device;
+        pub mod entry_key;
         pub mod error;
+        pub mod meter;
+        pub mod pipeline_config;
+        pub mod register;
         pub mod table;
+        pub use counter::Counter;
         pub use device::Device;
+        pub use entry_key::EntryKey;
+        pub use error::CounterError;
         pub use error::DeviceError;
+        pub use error::MeterError;
+        pub use error::PipelineError;
+        pub use error::RegisterError;
         pub use error::TableError;
+        pub use meter::Meter;
+        pub use pipeline_config::PipelineConfig;
+        pub use register::Register;
         pub use table::Table;
     }
 }
 
 pub mod utils {
+    pub mod bits;
     pub mod serde {
         pub mod cstring;
         pub mod error;
@@ -117,4 +148,10 @@ pub mod utils {
         pub use error::MmioError;
         pub use mmio::Mmio;
     }
+    pub mod packet {
+        pub mod error;
+        pub mod socket;
+        pub use error::PacketError;
+        pub use socket::PacketSocket;
+    }
 }
@@ -17,9 +17,21 @@ pub struct Config {
 pub struct ServerConfig {
     pub address: String,
     pub port: u16,
+
+    #[serde(rename = "readBatchEntities")]
+    #[serde(default = "ServerConfig::default_read_batch_entities")]
+    pub read_batch_entities: usize,
+
+    #[serde(rename = "readBatchBytes")]
+    #[serde(default = "ServerConfig::default_read_batch_bytes")]
+    pub read_batch_bytes: usize,
+
+    #[serde(rename = "stateDir")]
+    #[serde(default)]
+    pub state_dir: Option<PathBuf>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeviceConfig {
     pub id: u64,
     pub mmio: MmioConfig,
@@ -29,6 +41,28 @@ pub struct DeviceConfig {
 
     #[serde(rename = "targetConfig")]
     pub target_config: PathBuf,
+
+    #[serde(rename = "readMode")]
+    #[serde(default)]
+    pub read_mode: ReadMode,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReadMode {
+    #[default]
+    Shadow,
+    Hardware,
+}
+
+impl ServerConfig {
+    fn default_read_batch_entities() -> usize {
+        1000
+    }
+
+    fn default_read_batch_bytes() -> usize {
+        1024 * 1024
+    }
 }
 
 impl Config {
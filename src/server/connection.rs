@@ -1,48 +1,87 @@
 use crate::target::models::Device;
 use p4runtime::p4::v1::Uint128;
 use std::collections::HashMap;
+use tokio::sync::RwLock;
 
 #[derive(Debug, Clone)]
 pub struct Connection {
-    status: HashMap<u64, (Option<Uint128>, bool)>,
+    status: HashMap<u64, (String, Option<Uint128>, bool)>,
 }
 
 impl Connection {
-    pub fn new(devices: &HashMap<u64, Device>) -> Self {
-        let mut status: HashMap<u64, (Option<Uint128>, bool)> = HashMap::new();
-        for id in devices.keys() {
-            status.insert(*id, (None, false));
+    pub fn new(devices: &HashMap<u64, RwLock<Device>>) -> Self {
+        Connection::from_device_ids(devices.keys().copied())
+    }
+
+    pub fn from_device_ids(device_ids: impl IntoIterator<Item = u64>) -> Self {
+        let mut status: HashMap<u64, (String, Option<Uint128>, bool)> = HashMap::new();
+        for id in device_ids {
+            status.insert(id, (String::new(), None, false));
         }
         Self { status: status }
     }
 
+    pub fn get_role(&self, device_id: u64) -> String {
+        self.status.get(&device_id).unwrap().0.clone()
+    }
+
     pub fn get_election_id(&self, device_id: u64) -> Option<Uint128> {
-        self.status.get(&device_id).unwrap().0
+        self.status.get(&device_id).unwrap().1
     }
 
     pub fn subscribed(&self, device_id: u64) -> bool {
-        self.status.get(&device_id).unwrap().1
+        self.status.get(&device_id).unwrap().2
     }
 
-    pub fn subscribe(&mut self, device_id: u64, election_id: Uint128) {
-        *self.status.get_mut(&device_id).unwrap() = (Some(election_id), true);
+    pub fn subscribe(&mut self, device_id: u64, role: String, election_id: Uint128) {
+        *self.status.get_mut(&device_id).unwrap() = (role, Some(election_id), true);
     }
 
-    pub fn resubscribe(&mut self, device_id: u64, election_id: Uint128) {
-        self.subscribe(device_id, election_id);
+    pub fn resubscribe(&mut self, device_id: u64, role: String, election_id: Uint128) {
+        self.subscribe(device_id, role, election_id);
     }
 
     pub fn unsubscribe(&mut self, device_id: u64) {
-        *self.status.get_mut(&device_id).unwrap() = (None, false);
+        *self.status.get_mut(&device_id).unwrap() = (String::new(), None, false);
+    }
+
+    pub fn get_device_id(&self) -> Option<u64> {
+        let mut device_ids: Vec<u64> = self.get_subscribed_list().keys().copied().collect();
+        device_ids.sort();
+        device_ids.first().copied()
     }
 
-    pub fn get_subscribed_list(&self) -> HashMap<u64, Uint128> {
-        let mut subscribed_list: HashMap<u64, Uint128> = HashMap::new();
-        for (device_id, (election_id, _)) in self.status.iter() {
+    pub fn get_subscribed_list(&self) -> HashMap<u64, (String, Uint128)> {
+        let mut subscribed_list: HashMap<u64, (String, Uint128)> = HashMap::new();
+        for (device_id, (role, election_id, _)) in self.status.iter() {
             if election_id.is_some() {
-                subscribed_list.insert(*device_id, election_id.unwrap());
+                subscribed_list.insert(*device_id, (role.clone(), election_id.unwrap()));
             }
         }
         return subscribed_list;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Connection;
+    use p4runtime::p4::v1::Uint128;
+
+    #[test]
+    fn subscribe_and_unsubscribe() {
+        let mut connection = Connection::from_device_ids([1, 2]);
+        assert_eq!(connection.get_device_id(), None);
+        assert!(!connection.subscribed(2));
+
+        connection.subscribe(2, "role".to_string(), Uint128 { high: 0, low: 7 });
+        assert!(connection.subscribed(2));
+        assert_eq!(connection.get_device_id(), Some(2));
+        assert_eq!(connection.get_role(2), "role");
+        assert_eq!(connection.get_election_id(2), Some(Uint128 { high: 0, low: 7 }));
+
+        connection.unsubscribe(2);
+        assert!(!connection.subscribed(2));
+        assert_eq!(connection.get_device_id(), None);
+        assert!(connection.get_subscribed_list().is_empty());
+    }
+}
@@ -1,3 +1,9 @@
+use crate::target::driver::XilVitisNetP4ReturnType;
+use crate::target::models::CounterError;
+use crate::target::models::MeterError;
+use crate::target::models::RegisterError;
+use crate::target::models::TableError;
+use p4runtime::google::rpc::Code;
 use std::path::PathBuf;
 use thiserror::Error;
 
@@ -14,6 +20,26 @@ pub enum ConfigError {
     SerdeYaml(#[from] serde_yaml::Error),
 }
 
+#[derive(Debug, Error)]
+pub enum PipelineStoreError {
+    #[error("Failed to read '{path}'")]
+    FileRead {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Failed to write '{path}'")]
+    FileWrite {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Failed to decode pipeline config")]
+    Decode(#[from] prost::DecodeError),
+}
+
 #[derive(Debug, Error)]
 pub enum RunServerProcessError {
     #[error("Failed to config")]
@@ -28,3 +54,74 @@ pub enum RunServerProcessError {
     #[error("Failed to service")]
     Service(Box<dyn std::error::Error>),
 }
+
+#[derive(Debug, Error)]
+pub enum WriteError {
+    #[error("Invalid update type {0}")]
+    InvalidUpdateType(i32),
+
+    #[error("Table {0} not found")]
+    NotFoundTable(u32),
+
+    #[error("Counter {0} not found")]
+    NotFoundCounter(u32),
+
+    #[error("Register {0} not found")]
+    NotFoundRegister(u32),
+
+    #[error("Meter {0} not found")]
+    NotFoundMeter(u32),
+
+    #[error("Entity type not supported")]
+    NotSupportedEntity,
+
+    #[error("Entity type cannot be rolled back")]
+    NotSupportedRollback,
+
+    #[error("Aborted due to a previous error")]
+    Aborted,
+
+    #[error("Role {0:?} may not access entity {1}")]
+    PermissionDenied(String, u32),
+
+    #[error("Failed to apply table entry: {0}")]
+    Table(#[from] TableError),
+
+    #[error("Failed to apply counter entry: {0}")]
+    Counter(#[from] CounterError),
+
+    #[error("Failed to apply register entry: {0}")]
+    Register(#[from] RegisterError),
+
+    #[error("Failed to apply meter entry: {0}")]
+    Meter(#[from] MeterError),
+}
+
+impl WriteError {
+    pub fn as_rpc_code(&self) -> Code {
+        match self {
+            WriteError::InvalidUpdateType(_) => Code::InvalidArgument,
+            WriteError::NotFoundTable(_) => Code::NotFound,
+            WriteError::NotFoundCounter(_) => Code::NotFound,
+            WriteError::NotFoundRegister(_) => Code::NotFound,
+            WriteError::NotFoundMeter(_) => Code::NotFound,
+            WriteError::NotSupportedEntity => Code::Unimplemented,
+            WriteError::NotSupportedRollback => Code::Unimplemented,
+            WriteError::Aborted => Code::Aborted,
+            WriteError::PermissionDenied(_, _) => Code::PermissionDenied,
+            WriteError::Table(e) => e.as_rpc_code(),
+            WriteError::Counter(e) => e.as_rpc_code(),
+            WriteError::Register(e) => e.as_rpc_code(),
+            WriteError::Meter(e) => e.as_rpc_code(),
+        }
+    }
+
+    pub fn as_driver_code(&self) -> Option<XilVitisNetP4ReturnType> {
+        match self {
+            WriteError::Table(e) => e.as_driver_code(),
+            WriteError::Counter(e) => e.as_driver_code(),
+            WriteError::Register(e) => e.as_driver_code(),
+            _ => None,
+        }
+    }
+}
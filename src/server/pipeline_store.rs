@@ -0,0 +1,48 @@
+use super::error::PipelineStoreError;
+use p4runtime::p4::v1::ForwardingPipelineConfig;
+use prost::Message;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+/// Keeps the last committed `ForwardingPipelineConfig` of each device in `<state_dir>/device-<id>.pb`.
+#[derive(Debug, Clone)]
+pub struct PipelineStore {
+    state_dir: PathBuf,
+}
+
+impl PipelineStore {
+    pub fn new(state_dir: PathBuf) -> Self {
+        Self { state_dir: state_dir }
+    }
+
+    pub fn load(&self, device_id: u64) -> Result<Option<ForwardingPipelineConfig>, PipelineStoreError> {
+        let path = self.get_path(device_id);
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(PipelineStoreError::FileRead { path: path, source: e }),
+        };
+        Ok(Some(ForwardingPipelineConfig::decode(bytes.as_slice())?))
+    }
+
+    /// Writes to a temporary file first so a crash never leaves a truncated config behind.
+    pub fn save(&self, device_id: u64, config: &ForwardingPipelineConfig) -> Result<(), PipelineStoreError> {
+        fs::create_dir_all(&self.state_dir).map_err(|e| PipelineStoreError::FileWrite {
+            path: self.state_dir.clone(),
+            source: e,
+        })?;
+        let path = self.get_path(device_id);
+        let temporary_path = path.with_extension("pb.tmp");
+        fs::write(&temporary_path, config.encode_to_vec()).map_err(|e| PipelineStoreError::FileWrite {
+            path: temporary_path.clone(),
+            source: e,
+        })?;
+        fs::rename(&temporary_path, &path).map_err(|e| PipelineStoreError::FileWrite { path: path, source: e })?;
+        Ok(())
+    }
+
+    fn get_path(&self, device_id: u64) -> PathBuf {
+        self.state_dir.join(format!("device-{}.pb", device_id))
+    }
+}
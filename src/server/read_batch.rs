@@ -0,0 +1,117 @@
+use p4runtime::p4::v1::Entity;
+use p4runtime::p4::v1::ReadResponse;
+use prost::Message;
+
+#[derive(Debug, Clone)]
+pub struct ReadBatch {
+    max_entities: usize,
+    max_bytes: usize,
+    entities: Vec<Entity>,
+    bytes: usize,
+}
+
+impl ReadBatch {
+    pub fn new(max_entities: usize, max_bytes: usize) -> Self {
+        Self {
+            max_entities: max_entities.max(1),
+            max_bytes: max_bytes,
+            entities: Vec::new(),
+            bytes: 0,
+        }
+    }
+
+    pub fn push(&mut self, entity: Entity) -> Option<ReadResponse> {
+        let entity_bytes = entity.encoded_len();
+        let response = if !self.entities.is_empty() && (self.entities.len() >= self.max_entities || self.bytes + entity_bytes > self.max_bytes) {
+            self.finish()
+        } else {
+            None
+        };
+        self.bytes += entity_bytes;
+        self.entities.push(entity);
+        return response;
+    }
+
+    pub fn finish(&mut self) -> Option<ReadResponse> {
+        if self.entities.is_empty() {
+            return None;
+        }
+        self.bytes = 0;
+        Some(ReadResponse {
+            entities: std::mem::take(&mut self.entities),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReadBatch;
+    use p4runtime::p4::v1::Entity;
+    use p4runtime::p4::v1::FieldMatch;
+    use p4runtime::p4::v1::ReadResponse;
+    use p4runtime::p4::v1::TableEntry;
+    use p4runtime::p4::v1::entity::Entity as EntityType;
+    use p4runtime::p4::v1::field_match::Exact;
+    use p4runtime::p4::v1::field_match::FieldMatchType;
+    use prost::Message;
+
+    fn table_entry(value: u32) -> Entity {
+        Entity {
+            entity: Some(EntityType::TableEntry(TableEntry {
+                table_id: 0x02000001,
+                r#match: vec![FieldMatch {
+                    field_id: 1,
+                    field_match_type: Some(FieldMatchType::Exact(Exact { value: value.to_be_bytes().to_vec() })),
+                }],
+                ..Default::default()
+            })),
+        }
+    }
+
+    fn read_all(batch: &mut ReadBatch, count: u32) -> Vec<ReadResponse> {
+        let mut responses: Vec<ReadResponse> = (0..count).filter_map(|value| batch.push(table_entry(value))).collect();
+        responses.extend(batch.finish());
+        responses
+    }
+
+    #[test]
+    fn splits_on_entity_limit() {
+        let mut batch = ReadBatch::new(3, usize::MAX);
+        let responses = read_all(&mut batch, 7);
+
+        let sizes: Vec<usize> = responses.iter().map(|response| response.entities.len()).collect();
+        assert_eq!(sizes, vec![3, 3, 1]);
+        assert!(batch.finish().is_none());
+    }
+
+    #[test]
+    fn splits_on_byte_limit() {
+        let entity_bytes = table_entry(0).encoded_len();
+        let mut batch = ReadBatch::new(usize::MAX, entity_bytes * 2);
+        let responses = read_all(&mut batch, 5);
+
+        let sizes: Vec<usize> = responses.iter().map(|response| response.entities.len()).collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn oversized_entity_is_sent_alone() {
+        let mut batch = ReadBatch::new(usize::MAX, 1);
+        let responses = read_all(&mut batch, 3);
+
+        assert_eq!(responses.len(), 3);
+        assert!(responses.iter().all(|response| response.entities.len() == 1));
+    }
+
+    #[test]
+    fn batches_many_entities_in_order() {
+        let mut batch = ReadBatch::new(1000, 8 * 1024);
+        let responses = read_all(&mut batch, 100_000);
+
+        assert!(responses.iter().all(|response| response.entities.len() <= 1000));
+        assert!(responses.iter().all(|response| response.entities.iter().map(|entity| entity.encoded_len()).sum::<usize>() <= 8 * 1024));
+        let entities: Vec<Entity> = responses.into_iter().flat_map(|response| response.entities).collect();
+        assert_eq!(entities.len(), 100_000);
+        assert!(entities.iter().enumerate().all(|(value, entity)| *entity == table_entry(value as u32)));
+    }
+}
@@ -0,0 +1,25 @@
+use pbjson_types::Any;
+use prost::Message;
+
+/// Wire-compatible with Stratum's `P4RoleConfig`, so controllers can reuse the role configs they already send.
+#[derive(Clone, PartialEq, Message)]
+pub struct RoleConfig {
+    #[prost(uint32, repeated, tag = "1")]
+    pub exclusive_p4_ids: Vec<u32>,
+    #[prost(uint32, repeated, tag = "2")]
+    pub shared_p4_ids: Vec<u32>,
+    #[prost(bool, tag = "4")]
+    pub receives_packet_ins: bool,
+    #[prost(bool, tag = "5")]
+    pub can_push_pipeline: bool,
+}
+
+impl RoleConfig {
+    pub fn from_any(any: &Any) -> Result<Self, prost::DecodeError> {
+        RoleConfig::decode(any.value.as_ref())
+    }
+
+    pub fn allows(&self, p4_id: u32) -> bool {
+        self.exclusive_p4_ids.contains(&p4_id) || self.shared_p4_ids.contains(&p4_id)
+    }
+}
@@ -1,30 +1,64 @@
 use crate::server::config::ServerConfig;
 use crate::server::connection::Connection;
+use crate::server::error::WriteError;
+use crate::server::pipeline_store::PipelineStore;
+use crate::server::read_batch::ReadBatch;
+use crate::server::role_config::RoleConfig;
 use crate::server::subscriber::Subscriber;
+use crate::server::subscribers::Subscribers;
+use crate::server::write_errors::WriteErrors;
+use crate::target::models::Counter;
 use crate::target::models::Device;
+use crate::target::models::Meter;
+use crate::target::models::PipelineError;
+use crate::target::models::Register;
+use crate::target::models::Table;
+use crate::utils::p4runtime::as_p4_id_from;
+use crate::utils::p4runtime::as_tonic_code;
+use crate::utils::p4runtime::as_u128_from;
 use crate::utils::p4runtime::get_forwarding_pipeline_config_request::as_response_type;
+use p4runtime::google::rpc::Code;
 use p4runtime::p4::v1::CapabilitiesRequest;
 use p4runtime::p4::v1::CapabilitiesResponse;
+use p4runtime::p4::v1::CounterEntry;
+use p4runtime::p4::v1::DirectMeterEntry;
 use p4runtime::p4::v1::Entity;
 use p4runtime::p4::v1::ForwardingPipelineConfig;
 use p4runtime::p4::v1::GetForwardingPipelineConfigRequest;
 use p4runtime::p4::v1::GetForwardingPipelineConfigResponse;
+use p4runtime::p4::v1::MasterArbitrationUpdate;
+use p4runtime::p4::v1::MeterEntry;
+use p4runtime::p4::v1::PacketIn;
+use p4runtime::p4::v1::PacketOut;
+use p4runtime::p4::v1::PacketOutError;
 use p4runtime::p4::v1::ReadRequest;
 use p4runtime::p4::v1::ReadResponse;
+use p4runtime::p4::v1::RegisterEntry;
 use p4runtime::p4::v1::SetForwardingPipelineConfigRequest;
 use p4runtime::p4::v1::SetForwardingPipelineConfigResponse;
+use p4runtime::p4::v1::StreamError;
 use p4runtime::p4::v1::StreamMessageRequest;
 use p4runtime::p4::v1::StreamMessageResponse;
+use p4runtime::p4::v1::TableEntry;
 use p4runtime::p4::v1::Uint128;
+use p4runtime::p4::v1::Update;
 use p4runtime::p4::v1::WriteRequest;
 use p4runtime::p4::v1::WriteResponse;
 use p4runtime::p4::v1::get_forwarding_pipeline_config_request::ResponseType;
 use p4runtime::p4::v1::p4_runtime_server::P4Runtime;
 use p4runtime::p4::v1::p4_runtime_server::P4RuntimeServer;
+use p4runtime::p4::v1::set_forwarding_pipeline_config_request::Action as PipelineAction;
+use p4runtime::p4::v1::stream_error::Details as StreamErrorDetails;
 use p4runtime::p4::v1::stream_message_request::Update as StreamMessageRequestUpdate;
+use p4runtime::p4::v1::stream_message_response::Update as StreamMessageResponseUpdate;
+use p4runtime::p4::v1::update::Type as UpdateType;
+use p4runtime::p4::v1::write_request::Atomicity;
 use std::collections::HashMap;
 use std::pin::Pin;
 use std::sync::Arc;
+use tokio::io::Interest;
+use tokio::io::unix::AsyncFd;
+use tokio::sync::RwLock;
 use tokio::sync::mpsc;
 use tokio_stream::Stream;
 use tokio_stream::StreamExt;
@@ -41,14 +75,16 @@ use tracing::warn;
 #[derive(Debug)]
 pub struct P4RuntimeService {
     config: Arc<ServerConfig>,
-    devices: Arc<HashMap<u64, Device>>,
+    devices: Arc<HashMap<u64, RwLock<Device>>>,
+    pipeline_store: Option<PipelineStore>,
 }
 
 impl P4RuntimeService {
     pub fn new(config: ServerConfig, devices: HashMap<u64, Device>) -> Self {
         Self {
+            pipeline_store: config.state_dir.clone().map(PipelineStore::new),
             config: Arc::new(config),
-            devices: Arc::new(devices),
+            devices: Arc::new(devices.into_iter().map(|(device_id, device)| (device_id, RwLock::new(device))).collect()),
         }
     }
 
@@ -57,10 +93,470 @@ impl P4RuntimeService {
 
         info!("Starting gRPC server on {}", endpoint);
 
+        self.restore_pipelines().await;
+        for (device_id, device_lock) in self.devices.iter() {
+            if device_lock.read().await.cpu_port.is_some() {
+                tokio::spawn(P4RuntimeService::receive_packets(Arc::clone(&self.devices), *device_id));
+            }
+        }
+
         Server::builder().add_service(P4RuntimeServer::new(self)).serve(endpoint).await?;
 
         Ok(())
     }
+
+    /// Re-applies the pipeline configs committed before the last restart.
+    async fn restore_pipelines(&self) {
+        let pipeline_store = match &self.pipeline_store {
+            Some(pipeline_store) => pipeline_store,
+            None => return,
+        };
+        for (device_id, device_lock) in self.devices.iter() {
+            let config = match pipeline_store.load(*device_id) {
+                Ok(Some(config)) => config,
+                Ok(None) => continue,
+                Err(e) => {
+                    error!(device_id = %device_id, error = %e, "Failed to load pipeline config");
+                    continue;
+                }
+            };
+            let mut device = device_lock.write().await;
+            let device_config_changed = device.pipeline_config.read().await.is_device_config_changed(&config);
+            let result = match device_config_changed {
+                true => device.commit(config, false).await,
+                false => {
+                    device.pipeline_config.write().await.commit(config);
+                    Ok(())
+                }
+            };
+            match result {
+                Ok(_) => info!(device_id = %device_id, "Restored pipeline config"),
+                Err(e) => error!(device_id = %device_id, error = %e, "Failed to restore pipeline config"),
+            }
+        }
+    }
+
+    async fn save_pipeline(&self, device_id: u64, device: &Device) {
+        let pipeline_store = match &self.pipeline_store {
+            Some(pipeline_store) => pipeline_store,
+            None => return,
+        };
+        let config = device.pipeline_config.read().await.committed.clone();
+        if let Err(e) = pipeline_store.save(device_id, &config) {
+            error!(device_id = %device_id, error = %e, "Failed to save pipeline config");
+        }
+    }
+
+    /// The cpu port survives pipeline reloads, so the device is looked up again for every frame.
+    async fn receive_packets(devices: Arc<HashMap<u64, RwLock<Device>>>, device_id: u64) {
+        let cpu_port = Arc::clone(devices.get(&device_id).unwrap().read().await.cpu_port.as_ref().unwrap());
+        let cpu_port = match AsyncFd::with_interest(cpu_port, Interest::READABLE) {
+            Ok(cpu_port) => cpu_port,
+            Err(e) => {
+                error!(device_id = %device_id, error = %e, "Failed to register cpu port");
+                return;
+            }
+        };
+
+        info!(device_id = %device_id, cpu_port = %cpu_port.get_ref().name(), "Receiving packets from cpu port");
+        let mut buffer = vec![0u8; 65536];
+        loop {
+            let mut guard = match cpu_port.readable().await {
+                Ok(guard) => guard,
+                Err(e) => {
+                    error!(device_id = %device_id, error = %e, "Failed to poll cpu port");
+                    return;
+                }
+            };
+            let length = match guard.try_io(|inner| inner.get_ref().recv(&mut buffer)) {
+                Ok(Ok(Some(length))) => length,
+                Ok(Ok(None)) | Err(_) => continue,
+                Ok(Err(e)) => {
+                    error!(device_id = %device_id, error = %e, "Failed to receive from cpu port");
+                    return;
+                }
+            };
+
+            let device = devices.get(&device_id).unwrap().read().await;
+            let packet = match device.target_schema.get_controller_header("packet_in") {
+                Some(controller_header) => match controller_header.decode(&buffer[..length]) {
+                    Some((metadata, payload)) => PacketIn { payload: payload, metadata: metadata },
+                    None => {
+                        warn!(device_id = %device_id, length = %length, "Frame shorter than packet_in header, dropped");
+                        continue;
+                    }
+                },
+                None => PacketIn {
+                    payload: buffer[..length].to_vec(),
+                    metadata: Vec::new(),
+                },
+            };
+            let response = StreamMessageResponse {
+                update: Some(StreamMessageResponseUpdate::Packet(packet)),
+            };
+            device.subscribers.read().await.notify_primaries(response, None);
+        }
+    }
+
+    async fn send_packet(device: &Device, role: &str, election_id: Uint128, packet: PacketOut) -> Result<(), StreamError> {
+        let error = |code: Code, message: String, packet: PacketOut| StreamError {
+            canonical_code: code.into(),
+            message: message,
+            space: "".to_string(),
+            code: 0,
+            details: Some(StreamErrorDetails::PacketOut(PacketOutError { packet_out: Some(packet) })),
+        };
+
+        if !device.subscribers.read().await.is_primary(role, Some(election_id)) {
+            return Err(error(Code::PermissionDenied, format!("Client is not the primary for device {}", device.id), packet));
+        }
+        let cpu_port = match &device.cpu_port {
+            Some(cpu_port) => cpu_port,
+            None => return Err(error(Code::FailedPrecondition, format!("Device {} has no cpu port", device.id), packet)),
+        };
+        let frame = match device.target_schema.get_controller_header("packet_out") {
+            Some(controller_header) => match controller_header.encode(&packet.metadata, &packet.payload) {
+                Ok(frame) => frame,
+                Err(e) => return Err(error(Code::InvalidArgument, e.to_string(), packet)),
+            },
+            None => packet.payload.clone(),
+        };
+        if let Err(e) = cpu_port.send(&frame) {
+            return Err(error(Code::Unavailable, format!("Failed to send to cpu port: {}", e), packet));
+        }
+        Ok(())
+    }
+
+    /// Handles a `MasterArbitrationUpdate`; an error is fatal and closes the stream.
+    async fn arbitrate(
+        devices: &HashMap<u64, RwLock<Device>>,
+        connection: &mut Connection,
+        arbitration: MasterArbitrationUpdate,
+        sender: &mpsc::Sender<Result<StreamMessageResponse, Status>>,
+    ) -> Result<(), Status> {
+        let device_id = arbitration.device_id;
+        let device = devices.get(&device_id).ok_or_else(|| Status::not_found(format!("Device {} not found", device_id)))?.read().await;
+        P4RuntimeService::arbitrate_subscribers(&device.subscribers, connection, arbitration, sender).await
+    }
+
+    async fn arbitrate_subscribers(
+        subscribers: &RwLock<Subscribers>,
+        connection: &mut Connection,
+        arbitration: MasterArbitrationUpdate,
+        sender: &mpsc::Sender<Result<StreamMessageResponse, Status>>,
+    ) -> Result<(), Status> {
+        let device_id = arbitration.device_id;
+        if let Some(subscribed_device_id) = connection.get_device_id() {
+            if subscribed_device_id != device_id {
+                return Err(Status::failed_precondition(format!(
+                    "Stream is already arbitrated for device {}, not {}",
+                    subscribed_device_id, device_id
+                )));
+            }
+        }
+        let (role, role_config) = match &arbitration.role {
+            Some(role) => {
+                let role_config = role
+                    .config
+                    .as_ref()
+                    .map(RoleConfig::from_any)
+                    .transpose()
+                    .map_err(|e| Status::invalid_argument(format!("Invalid config for role {:?}: {}", role.name, e)))?;
+                (role.name.clone(), role_config)
+            }
+            None => (String::new(), None),
+        };
+        let election_id = arbitration.election_id.unwrap_or(Uint128 { high: 0, low: 0 });
+        let subscriber = Subscriber::new(role.clone(), role_config, election_id, sender.clone());
+
+        let mut subscribers = subscribers.write().await;
+        if let Some(role_config) = &subscriber.role_config {
+            if let Some((other_role, p4_id)) = subscribers.find_exclusive_conflict(&role, role_config) {
+                return Err(Status::invalid_argument(format!(
+                    "Role {:?} claims id {} exclusively, but it is already exclusive to role {:?}",
+                    role, p4_id, other_role
+                )));
+            }
+        }
+        if connection.subscribed(device_id) {
+            let old_role = connection.get_role(device_id);
+            let old_election_id = connection.get_election_id(device_id).unwrap();
+            if old_role == role && old_election_id == election_id {
+                subscribers.insert(&subscriber);
+                return Ok(());
+            }
+            if subscribers.find(&role, election_id).is_some() {
+                return Err(Status::invalid_argument(format!(
+                    "Election id {} is already used by another client for role {:?}",
+                    as_u128_from(&election_id),
+                    role
+                )));
+            }
+            subscribers.delete(&old_role, old_election_id);
+            subscribers.insert(&subscriber);
+            connection.resubscribe(device_id, role, election_id);
+        } else {
+            if subscribers.find(&role, election_id).is_some() {
+                return Err(Status::invalid_argument(format!(
+                    "Election id {} is already used by another client for role {:?}",
+                    as_u128_from(&election_id),
+                    role
+                )));
+            }
+            subscribers.insert(&subscriber);
+            connection.subscribe(device_id, role, election_id);
+        }
+        Ok(())
+    }
+
+    /// Drops every subscription of a connection whose stream has ended.
+    async fn unsubscribe_all(devices: &HashMap<u64, RwLock<Device>>, connection: &Connection) {
+        for device_id in connection.get_subscribed_list().keys() {
+            let device = devices.get(device_id).unwrap().read().await;
+            P4RuntimeService::unsubscribe_subscribers(&device.subscribers, connection, *device_id).await;
+        }
+    }
+
+    async fn unsubscribe_subscribers(subscribers: &RwLock<Subscribers>, connection: &Connection, device_id: u64) {
+        if let Some((role, election_id)) = connection.get_subscribed_list().get(&device_id) {
+            subscribers.write().await.delete(role, *election_id);
+        }
+    }
+
+    /// Commits `config`, which rebuilds the device.
+    /// The write lock keeps every other request off the device until the swap is done.
+    async fn commit_pipeline(device_lock: &RwLock<Device>, config: ForwardingPipelineConfig, reconcile: bool) -> Result<(), PipelineError> {
+        device_lock.write().await.commit(config, reconcile).await
+    }
+
+    /// Returns the config of the primary for `role`, if the role has one.
+    async fn check_primary(&self, device: &Device, role: &str, election_id: Option<Uint128>) -> Result<Option<RoleConfig>, Status> {
+        let subscribers = device.subscribers.read().await;
+        let primary = match subscribers.get_primary(role) {
+            Some(primary) => primary,
+            None => return Err(Status::permission_denied(format!("No primary client for role {:?} on device {}", role, device.id))),
+        };
+        if !subscribers.is_primary(role, election_id) {
+            return Err(Status::permission_denied(format!("Client is not the primary for role {:?} on device {}", role, device.id)));
+        }
+        if role.is_empty() {
+            return Ok(None);
+        }
+        Ok(primary.role_config.clone())
+    }
+
+    async fn read_table_entries(tables: &HashMap<u32, RwLock<Table>>, filter: TableEntry) -> Result<Vec<Entity>, Status> {
+        let table_ids: Vec<u32> = if filter.table_id == 0 {
+            let mut table_ids: Vec<u32> = tables.keys().copied().collect();
+            table_ids.sort();
+            table_ids
+        } else if tables.contains_key(&filter.table_id) {
+            vec![filter.table_id]
+        } else {
+            return Err(Status::not_found(format!("Table {} not found", filter.table_id)));
+        };
+
+        let mut entities: Vec<Entity> = Vec::new();
+        for table_id in table_ids {
+            let table_lock = tables.get(&table_id).unwrap();
+            if table_lock.read().await.needs_reconcile(&filter) {
+                let mut table = table_lock.write().await;
+                table.reconcile().map_err(|e| Status::new(as_tonic_code(e.as_rpc_code()), e.to_string()))?;
+            }
+            let table = table_lock.read().await;
+            let table_entries = table.read(&filter).map_err(|e| Status::new(as_tonic_code(e.as_rpc_code()), e.to_string()))?;
+            for table_entry in table_entries {
+                entities.push(Entity {
+                    entity: Some(p4runtime::p4::v1::entity::Entity::TableEntry(table_entry)),
+                });
+            }
+        }
+        Ok(entities)
+    }
+
+    async fn read_counter_entries(counters: &HashMap<u32, RwLock<Counter>>, filter: CounterEntry) -> Result<Vec<Entity>, Status> {
+        let counter_ids: Vec<u32> = if filter.counter_id == 0 {
+            let mut counter_ids: Vec<u32> = counters.keys().copied().collect();
+            counter_ids.sort();
+            counter_ids
+        } else if counters.contains_key(&filter.counter_id) {
+            vec![filter.counter_id]
+        } else {
+            return Err(Status::not_found(format!("Counter {} not found", filter.counter_id)));
+        };
+
+        let mut entities: Vec<Entity> = Vec::new();
+        for counter_id in counter_ids {
+            let counter = counters.get(&counter_id).unwrap().read().await;
+            let counter_entries = counter.read(&filter).map_err(|e| Status::new(as_tonic_code(e.as_rpc_code()), e.to_string()))?;
+            for counter_entry in counter_entries {
+                entities.push(Entity {
+                    entity: Some(p4runtime::p4::v1::entity::Entity::CounterEntry(counter_entry)),
+                });
+            }
+        }
+        Ok(entities)
+    }
+
+    async fn read_register_entries(registers: &HashMap<u32, RwLock<Register>>, filter: RegisterEntry) -> Result<Vec<Entity>, Status> {
+        let register_ids: Vec<u32> = if filter.register_id == 0 {
+            let mut register_ids: Vec<u32> = registers.keys().copied().collect();
+            register_ids.sort();
+            register_ids
+        } else if registers.contains_key(&filter.register_id) {
+            vec![filter.register_id]
+        } else {
+            return Err(Status::not_found(format!("Register {} not found", filter.register_id)));
+        };
+
+        let mut entities: Vec<Entity> = Vec::new();
+        for register_id in register_ids {
+            let register = registers.get(&register_id).unwrap().read().await;
+            let register_entries = register.read(&filter).map_err(|e| Status::new(as_tonic_code(e.as_rpc_code()), e.to_string()))?;
+            for register_entry in register_entries {
+                entities.push(Entity {
+                    entity: Some(p4runtime::p4::v1::entity::Entity::RegisterEntry(register_entry)),
+                });
+            }
+        }
+        Ok(entities)
+    }
+
+    async fn read_meter_entries(meters: &HashMap<u32, RwLock<Meter>>, filter: MeterEntry) -> Result<Vec<Entity>, Status> {
+        let mut meter_ids: Vec<u32> = Vec::new();
+        if filter.meter_id == 0 {
+            for (meter_id, meter_lock) in meters.iter() {
+                if meter_lock.read().await.schema.direct_table_id.is_none() {
+                    meter_ids.push(*meter_id);
+                }
+            }
+            meter_ids.sort();
+        } else if meters.contains_key(&filter.meter_id) {
+            meter_ids.push(filter.meter_id);
+        } else {
+            return Err(Status::not_found(format!("Meter {} not found", filter.meter_id)));
+        }
+
+        let mut entities: Vec<Entity> = Vec::new();
+        for meter_id in meter_ids {
+            let meter = meters.get(&meter_id).unwrap().read().await;
+            let meter_entries = meter.read(&filter).map_err(|e| Status::new(as_tonic_code(e.as_rpc_code()), e.to_string()))?;
+            for meter_entry in meter_entries {
+                entities.push(Entity {
+                    entity: Some(p4runtime::p4::v1::entity::Entity::MeterEntry(meter_entry)),
+                });
+            }
+        }
+        Ok(entities)
+    }
+
+    async fn read_direct_meter_entries(meters: &HashMap<u32, RwLock<Meter>>, filter: DirectMeterEntry) -> Result<Vec<Entity>, Status> {
+        let table_id = filter.table_entry.as_ref().map(|table_entry| table_entry.table_id).unwrap_or_default();
+        let mut meter_ids: Vec<u32> = Vec::new();
+        for (meter_id, meter_lock) in meters.iter() {
+            match meter_lock.read().await.schema.direct_table_id {
+                Some(direct_table_id) if table_id == 0 || direct_table_id == table_id => meter_ids.push(*meter_id),
+                _ => {}
+            }
+        }
+        if table_id != 0 && meter_ids.is_empty() {
+            return Err(Status::not_found(format!("Table {} has no direct meter", table_id)));
+        }
+        meter_ids.sort();
+
+        let mut entities: Vec<Entity> = Vec::new();
+        for meter_id in meter_ids {
+            let meter = meters.get(&meter_id).unwrap().read().await;
+            let direct_meter_entries = meter.read_direct(&filter).map_err(|e| Status::new(as_tonic_code(e.as_rpc_code()), e.to_string()))?;
+            for direct_meter_entry in direct_meter_entries {
+                entities.push(Entity {
+                    entity: Some(p4runtime::p4::v1::entity::Entity::DirectMeterEntry(direct_meter_entry)),
+                });
+            }
+        }
+        Ok(entities)
+    }
+
+    /// Meters keep no previous config, so their updates cannot be undone by ROLLBACK_ON_ERROR.
+    fn has_inverse(update: &Update) -> bool {
+        !matches!(
+            update.entity.as_ref().and_then(|entity| entity.entity.as_ref()),
+            Some(p4runtime::p4::v1::entity::Entity::MeterEntry(_)) | Some(p4runtime::p4::v1::entity::Entity::DirectMeterEntry(_))
+        )
+    }
+
+    async fn apply_update(&self, device: &Device, update: Update) -> Result<Vec<Update>, WriteError> {
+        let update_type = UpdateType::try_from(update.r#type).map_err(|_| WriteError::InvalidUpdateType(update.r#type))?;
+        if update_type == UpdateType::Unspecified {
+            return Err(WriteError::InvalidUpdateType(update.r#type));
+        }
+
+        match update.entity.and_then(|e| e.entity) {
+            Some(p4runtime::p4::v1::entity::Entity::TableEntry(table_entry)) => {
+                let table_id = table_entry.table_id;
+                let table_lock = device.tables.get(&table_id).ok_or(WriteError::NotFoundTable(table_id))?;
+                let mut table = table_lock.write().await;
+                let (inverse_entry, inverse_type) = table.get_inverse(&table_entry, update_type)?;
+                table.apply(table_entry, update_type)?;
+                Ok(vec![Update {
+                    r#type: inverse_type.into(),
+                    entity: Some(Entity {
+                        entity: Some(p4runtime::p4::v1::entity::Entity::TableEntry(inverse_entry)),
+                    }),
+                }])
+            }
+            Some(p4runtime::p4::v1::entity::Entity::CounterEntry(counter_entry)) => {
+                let counter_id = counter_entry.counter_id;
+                let counter_lock = device.counters.get(&counter_id).ok_or(WriteError::NotFoundCounter(counter_id))?;
+                let mut counter = counter_lock.write().await;
+                let inverse_entries = counter.get_inverse(&counter_entry)?;
+                counter.apply(counter_entry, update_type)?;
+                Ok(inverse_entries
+                    .into_iter()
+                    .map(|inverse_entry| Update {
+                        r#type: UpdateType::Modify.into(),
+                        entity: Some(Entity {
+                            entity: Some(p4runtime::p4::v1::entity::Entity::CounterEntry(inverse_entry)),
+                        }),
+                    })
+                    .collect())
+            }
+            Some(p4runtime::p4::v1::entity::Entity::RegisterEntry(register_entry)) => {
+                let register_id = register_entry.register_id;
+                let register_lock = device.registers.get(&register_id).ok_or(WriteError::NotFoundRegister(register_id))?;
+                let mut register = register_lock.write().await;
+                let inverse_entries = register.get_inverse(&register_entry)?;
+                register.apply(register_entry, update_type)?;
+                Ok(inverse_entries
+                    .into_iter()
+                    .map(|inverse_entry| Update {
+                        r#type: UpdateType::Modify.into(),
+                        entity: Some(Entity {
+                            entity: Some(p4runtime::p4::v1::entity::Entity::RegisterEntry(inverse_entry)),
+                        }),
+                    })
+                    .collect())
+            }
+            Some(p4runtime::p4::v1::entity::Entity::MeterEntry(meter_entry)) => {
+                let meter_id = meter_entry.meter_id;
+                let meter_lock = device.meters.get(&meter_id).ok_or(WriteError::NotFoundMeter(meter_id))?;
+                meter_lock.write().await.apply(meter_entry, update_type)?;
+                Ok(Vec::new())
+            }
+            Some(p4runtime::p4::v1::entity::Entity::DirectMeterEntry(direct_meter_entry)) => {
+                let table_id = direct_meter_entry.table_entry.as_ref().map(|table_entry| table_entry.table_id).unwrap_or_default();
+                for meter_lock in device.meters.values() {
+                    let mut meter = meter_lock.write().await;
+                    if meter.schema.direct_table_id == Some(table_id) {
+                        meter.apply_direct(direct_meter_entry, update_type)?;
+                        return Ok(Vec::new());
+                    }
+                }
+                Err(WriteError::NotFoundTable(table_id))
+            }
+            _ => Err(WriteError::NotSupportedEntity),
+        }
+    }
 }
 
 #[tonic::async_trait]
@@ -72,31 +568,63 @@ impl P4Runtime for P4RuntimeService {
         let req = request.into_inner();
 
         let device_id = req.device_id;
-        let device = self.devices.get(&device_id).ok_or_else(|| Status::not_found(format!("Device {} not found", device_id)))?;
-        for update in req.updates {
-            let update_type = p4runtime::p4::v1::update::Type::try_from(update.r#type).map_err(|_| Status::invalid_argument("Invalid update type"))?;
-
-            match update.entity.and_then(|e| e.entity) {
-                Some(p4runtime::p4::v1::entity::Entity::TableEntry(table_entry)) => {
-                    let table_id = table_entry.table_id;
-                    let table_lock = device.tables.get(&table_id).ok_or_else(|| Status::not_found(format!("Table {} not found", table_id)))?;
-                    let mut table = table_lock.write().await;
-                    if let Err(e) = table.apply(table_entry, update_type) {
-                        error!(
-                            device_id = %device_id,
-                            table_id = %table_id,
-                            error = %e,
-                            "Failed to apply table entry"
-                        );
-                        return Err(Status::internal(format!("Failed to apply table entry: {}", e)));
-                    }
+        let device = self.devices.get(&device_id).ok_or_else(|| Status::not_found(format!("Device {} not found", device_id)))?.read().await;
+        let role_config = self.check_primary(&device, &req.role, req.election_id).await?;
+        let atomicity = Atomicity::try_from(req.atomicity).map_err(|_| Status::invalid_argument(format!("Invalid atomicity {}", req.atomicity)))?;
+        if atomicity == Atomicity::DataplaneAtomic {
+            return Err(Status::unimplemented("DATAPLANE_ATOMIC is not supported"));
+        }
+
+        let mut errors = WriteErrors::new();
+        let mut inverse_updates: Vec<(usize, Update)> = Vec::new();
+        for (index, update) in req.updates.into_iter().enumerate() {
+            if atomicity == Atomicity::RollbackOnError && errors.failed() {
+                errors.push(Err(WriteError::Aborted));
+                continue;
+            }
+            if let Some(role_config) = &role_config {
+                let p4_id = update.entity.as_ref().and_then(as_p4_id_from);
+                if let Some(p4_id) = p4_id.filter(|p4_id| !role_config.allows(*p4_id)) {
+                    errors.push(Err(WriteError::PermissionDenied(req.role.clone(), p4_id)));
+                    continue;
+                }
+            }
+            if atomicity == Atomicity::RollbackOnError && !P4RuntimeService::has_inverse(&update) {
+                errors.push(Err(WriteError::NotSupportedRollback));
+                continue;
+            }
+            match self.apply_update(&device, update).await {
+                Ok(inverse) => {
+                    inverse_updates.extend(inverse.into_iter().map(|inverse_update| (index, inverse_update)));
+                    errors.push(Ok(()));
                 }
-                _ => {
-                    return Err(Status::unimplemented("Entity type not supported"));
+                Err(e) => {
+                    error!(
+                        device_id = %device_id,
+                        error = %e,
+                        "Failed to apply update"
+                    );
+                    errors.push(Err(e));
                 }
             }
         }
 
+        if atomicity == Atomicity::RollbackOnError && errors.failed() {
+            let mut failed_rollbacks: HashMap<usize, String> = HashMap::new();
+            for (index, inverse_update) in inverse_updates.into_iter().rev() {
+                if let Err(e) = self.apply_update(&device, inverse_update).await {
+                    error!(
+                        device_id = %device_id,
+                        error = %e,
+                        "Failed to roll back update"
+                    );
+                    failed_rollbacks.insert(index, e.to_string());
+                }
+            }
+            errors.rollback(&failed_rollbacks);
+        }
+        errors.into_result()?;
+
         let response = WriteResponse::default();
         Ok(Response::new(response))
     }
@@ -105,49 +633,64 @@ impl P4Runtime for P4RuntimeService {
         let req = request.into_inner();
 
         let device_id = req.device_id;
-        let device = self.devices.get(&device_id).ok_or_else(|| Status::not_found(format!("Device {} not found", device_id)))?;
+        let device = self.devices.get(&device_id).ok_or_else(|| Status::not_found(format!("Device {} not found", device_id)))?.read().await;
+        let role_config = match req.role.is_empty() {
+            true => None,
+            false => device.subscribers.read().await.get_role_config(&req.role),
+        };
 
         let (tx, rx) = mpsc::channel(100);
+        drop(device);
         let entities = req.entities;
-        let tables = Arc::clone(&device.tables);
+        let devices = Arc::clone(&self.devices);
+        let mut batch = ReadBatch::new(self.config.read_batch_entities, self.config.read_batch_bytes);
 
         tokio::spawn(async move {
+            let device = devices.get(&device_id).unwrap().read().await;
+            let tables = Arc::clone(&device.tables);
+            let counters = Arc::clone(&device.counters);
+            let registers = Arc::clone(&device.registers);
+            let meters = Arc::clone(&device.meters);
             for entity in entities {
                 let response_entities = match entity.entity {
-                    Some(p4runtime::p4::v1::entity::Entity::TableEntry(table_entry)) => {
-                        let table_id = table_entry.table_id;
-                        let table_lock = match tables.get(&table_id) {
-                            Some(lock) => lock,
-                            None => {
-                                let _ = tx.send(Err(Status::not_found(format!("Table {} not found", table_id)))).await;
-                                return;
-                            }
-                        };
-                        let table = table_lock.read().await;
-                        table
-                            .entries
-                            .values()
-                            .cloned()
-                            .map(|table_entry| Entity {
-                                entity: Some(p4runtime::p4::v1::entity::Entity::TableEntry(table_entry)),
-                            })
-                            .collect()
-                    }
-                    _ => {
-                        vec![]
+                    Some(p4runtime::p4::v1::entity::Entity::TableEntry(table_entry)) => P4RuntimeService::read_table_entries(&tables, table_entry).await,
+                    Some(p4runtime::p4::v1::entity::Entity::CounterEntry(counter_entry)) => P4RuntimeService::read_counter_entries(&counters, counter_entry).await,
+                    Some(p4runtime::p4::v1::entity::Entity::RegisterEntry(register_entry)) => P4RuntimeService::read_register_entries(&registers, register_entry).await,
+                    Some(p4runtime::p4::v1::entity::Entity::MeterEntry(meter_entry)) => P4RuntimeService::read_meter_entries(&meters, meter_entry).await,
+                    Some(p4runtime::p4::v1::entity::Entity::DirectMeterEntry(direct_meter_entry)) => P4RuntimeService::read_direct_meter_entries(&meters, direct_meter_entry).await,
+                    _ => Err(Status::unimplemented("Entity type not supported")),
+                };
+
+                let response_entities = match response_entities {
+                    Ok(response_entities) => response_entities,
+                    Err(e) => {
+                        let _ = tx.send(Err(e)).await;
+                        return;
                     }
                 };
 
                 for resp_entity in response_entities {
-                    let read_response = ReadResponse { entities: vec![resp_entity] };
-
-                    if tx.send(Ok(read_response)).await.is_err() {
-                        warn!("Client disconnected during read");
-                        return;
+                    if let Some(role_config) = &role_config {
+                        if as_p4_id_from(&resp_entity).is_some_and(|p4_id| !role_config.allows(p4_id)) {
+                            continue;
+                        }
+                    }
+                    if let Some(read_response) = batch.push(resp_entity) {
+                        if tx.send(Ok(read_response)).await.is_err() {
+                            warn!("Client disconnected during read");
+                            return;
+                        }
                     }
                 }
             }
 
+            if let Some(read_response) = batch.finish() {
+                if tx.send(Ok(read_response)).await.is_err() {
+                    warn!("Client disconnected during read");
+                    return;
+                }
+            }
+
             info!("Read request processing completed");
         });
 
@@ -155,7 +698,50 @@ impl P4Runtime for P4RuntimeService {
         Ok(Response::new(Box::pin(stream)))
     }
 
-    async fn set_forwarding_pipeline_config(&self, _request: Request<SetForwardingPipelineConfigRequest>) -> Result<Response<SetForwardingPipelineConfigResponse>, Status> {
+    async fn set_forwarding_pipeline_config(&self, request: Request<SetForwardingPipelineConfigRequest>) -> Result<Response<SetForwardingPipelineConfigResponse>, Status> {
+        let req = request.into_inner();
+
+        let device_id = req.device_id;
+        let device_lock = self.devices.get(&device_id).ok_or_else(|| Status::not_found(format!("Device {} not found", device_id)))?;
+        let role_config = self.check_primary(&*device_lock.read().await, &req.role, req.election_id).await?;
+        if role_config.is_some_and(|role_config| !role_config.can_push_pipeline) {
+            return Err(Status::permission_denied(format!("Role {:?} may not push a pipeline", req.role)));
+        }
+        let action = PipelineAction::try_from(req.action).map_err(|_| Status::invalid_argument(format!("Invalid action {}", req.action)))?;
+        let to_status = |e: PipelineError| Status::new(as_tonic_code(e.as_rpc_code()), e.to_string());
+
+        match action {
+            PipelineAction::Verify => {
+                let config = req.config.ok_or_else(|| Status::invalid_argument("Missing config"))?;
+                device_lock.read().await.verify(&config).map_err(to_status)?;
+            }
+            PipelineAction::VerifyAndSave => {
+                let config = req.config.ok_or_else(|| Status::invalid_argument("Missing config"))?;
+                let device = device_lock.read().await;
+                device.verify(&config).map_err(to_status)?;
+                device.pipeline_config.write().await.save(config);
+            }
+            PipelineAction::VerifyAndCommit => {
+                let config = req.config.ok_or_else(|| Status::invalid_argument("Missing config"))?;
+                device_lock.read().await.verify(&config).map_err(to_status)?;
+                P4RuntimeService::commit_pipeline(device_lock, config, false).await.map_err(to_status)?;
+            }
+            PipelineAction::Commit => {
+                let config = device_lock.read().await.pipeline_config.write().await.take_saved().map_err(to_status)?;
+                P4RuntimeService::commit_pipeline(device_lock, config, false).await.map_err(to_status)?;
+            }
+            PipelineAction::ReconcileAndCommit => {
+                let config = req.config.ok_or_else(|| Status::invalid_argument("Missing config"))?;
+                device_lock.read().await.verify(&config).map_err(to_status)?;
+                P4RuntimeService::commit_pipeline(device_lock, config, true).await.map_err(to_status)?;
+            }
+            PipelineAction::Unspecified => return Err(Status::invalid_argument("Unspecified action")),
+        }
+        if action != PipelineAction::Verify && action != PipelineAction::VerifyAndSave {
+            self.save_pipeline(device_id, &*device_lock.read().await).await;
+        }
+        info!(device_id = %device_id, action = ?action, "Forwarding pipeline config applied");
+
         let response = SetForwardingPipelineConfigResponse {};
         Ok(Response::new(response))
     }
@@ -163,47 +749,30 @@ impl P4Runtime for P4RuntimeService {
     async fn get_forwarding_pipeline_config(&self, request: Request<GetForwardingPipelineConfigRequest>) -> Result<Response<GetForwardingPipelineConfigResponse>, Status> {
         let req = request.into_inner();
         let device_id = req.device_id;
-        let response_type: ResponseType = as_response_type(req.response_type).unwrap();
-        let device = self.devices.get(&device_id).ok_or_else(|| Status::not_found(format!("Device {} not found", device_id)))?;
-
-        match response_type {
-            ResponseType::All => {
-                let config = ForwardingPipelineConfig {
-                    p4info: Some(device.as_p4info()),
-                    p4_device_config: Vec::new(),
-                    cookie: None,
-                };
-                let response = GetForwardingPipelineConfigResponse { config: Some(config) };
-                return Ok(Response::new(response));
-            }
-            ResponseType::CookieOnly => {
-                let config = ForwardingPipelineConfig {
-                    p4info: None,
-                    p4_device_config: Vec::new(),
-                    cookie: None,
-                };
-                let response = GetForwardingPipelineConfigResponse { config: Some(config) };
-                return Ok(Response::new(response));
-            }
-            ResponseType::P4infoAndCookie => {
-                let config = ForwardingPipelineConfig {
-                    p4info: Some(device.as_p4info()),
-                    p4_device_config: Vec::new(),
-                    cookie: None,
-                };
-                let response = GetForwardingPipelineConfigResponse { config: Some(config) };
-                return Ok(Response::new(response));
-            }
-            ResponseType::DeviceConfigAndCookie => {
-                let config = ForwardingPipelineConfig {
-                    p4info: None,
-                    p4_device_config: Vec::new(),
-                    cookie: None,
-                };
-                let response = GetForwardingPipelineConfigResponse { config: Some(config) };
-                return Ok(Response::new(response));
-            }
-        }
+        let response_type: ResponseType = as_response_type(req.response_type).ok_or_else(|| Status::invalid_argument(format!("Invalid response type {}", req.response_type)))?;
+        let device = self.devices.get(&device_id).ok_or_else(|| Status::not_found(format!("Device {} not found", device_id)))?.read().await;
+        let committed = device.pipeline_config.read().await.committed.clone();
+
+        let config = match response_type {
+            ResponseType::All => committed,
+            ResponseType::CookieOnly => ForwardingPipelineConfig {
+                p4info: None,
+                p4_device_config: Vec::new(),
+                cookie: committed.cookie,
+            },
+            ResponseType::P4infoAndCookie => ForwardingPipelineConfig {
+                p4info: committed.p4info,
+                p4_device_config: Vec::new(),
+                cookie: committed.cookie,
+            },
+            ResponseType::DeviceConfigAndCookie => ForwardingPipelineConfig {
+                p4info: None,
+                p4_device_config: committed.p4_device_config,
+                cookie: committed.cookie,
+            },
+        };
+        let response = GetForwardingPipelineConfigResponse { config: Some(config) };
+        Ok(Response::new(response))
     }
 
     async fn stream_channel(&self, request: Request<Streaming<StreamMessageRequest>>) -> Result<Response<Self::StreamChannelStream>, Status> {
@@ -217,36 +786,36 @@ impl P4Runtime for P4RuntimeService {
         tokio::spawn(async move {
             while let Some(result) = inbound.next().await {
                 match result {
-                    Ok(message) => match message.update.unwrap() {
-                        StreamMessageRequestUpdate::Arbitration(arbitration) => {
-                            let device = devices.get(&arbitration.device_id);
-                            if device.is_none() {
-                                continue;
+                    Ok(message) => match message.update {
+                        Some(StreamMessageRequestUpdate::Arbitration(arbitration)) => {
+                            let device_id = arbitration.device_id;
+                            if let Err(status) = P4RuntimeService::arbitrate(&devices, &mut connection, arbitration, &tx).await {
+                                warn!(device_id = %device_id, error = %status.message(), "Arbitration failed, closing stream");
+                                let _ = responder.send(Err(status)).await;
+                                break;
                             }
-                            let device: &Device = device.unwrap();
-                            if connection.subscribed(arbitration.device_id) {
-                                let old_election_id = connection.get_election_id(arbitration.device_id).unwrap();
-                                let new_election_id = arbitration.election_id.unwrap_or(Uint128 { high: 0, low: 0 });
-                                let mut subscribers = device.subscribers.write().await;
-                                if subscribers.find_mut(new_election_id).is_some() {
-                                    continue;
-                                }
-                                subscribers.delete(old_election_id);
-                                let new_subscriber = Subscriber::new(new_election_id, tx.clone());
-                                subscribers.insert(&new_subscriber);
-                                connection.resubscribe(arbitration.device_id, new_election_id);
-                            } else {
-                                let election_id = arbitration.election_id.unwrap_or(Uint128 { high: 0, low: 0 });
-                                let mut subscribers = device.subscribers.write().await;
-                                if subscribers.find(election_id).is_some() {
+                        }
+                        Some(StreamMessageRequestUpdate::Packet(packet)) => {
+                            let device_id = match connection.get_device_id() {
+                                Some(device_id) => device_id,
+                                None => {
+                                    warn!("Packet out received before arbitration, dropped");
                                     continue;
                                 }
-                                let subscriber = Subscriber::new(election_id, tx.clone());
-                                subscribers.insert(&subscriber);
-                                connection.subscribe(arbitration.device_id, election_id);
+                            };
+                            let device = devices.get(&device_id).unwrap().read().await;
+                            let role = connection.get_role(device_id);
+                            let election_id = connection.get_election_id(device_id).unwrap();
+                            if let Err(stream_error) = P4RuntimeService::send_packet(&device, &role, election_id, packet).await {
+                                warn!(device_id = %device_id, error = %stream_error.message, "Failed to send packet out");
+                                let response = StreamMessageResponse {
+                                    update: Some(StreamMessageResponseUpdate::Error(stream_error)),
+                                };
+                                let _ = tx.send(Ok(response)).await;
                             }
                         }
-                        _ => {}
+                        Some(_) => {}
+                        None => warn!("Empty stream message ignored"),
                     },
                     Err(e) => {
                         let _ = responder.send(Err(e)).await;
@@ -254,12 +823,7 @@ impl P4Runtime for P4RuntimeService {
                     }
                 }
             }
-            for (device_id, election_id) in connection.get_subscribed_list().iter() {
-                let device = devices.get(&device_id).unwrap();
-
-                let mut subscribers = device.subscribers.write().await;
-                subscribers.delete(*election_id);
-            }
+            P4RuntimeService::unsubscribe_all(&devices, &connection).await;
             info!("Client stream channel processing ended");
         });
         let stream = ReceiverStream::new(rx);
@@ -274,3 +838,163 @@ impl P4Runtime for P4RuntimeService {
         Ok(Response::new(response))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::P4RuntimeService;
+    use crate::server::connection::Connection;
+    use crate::server::read_batch::ReadBatch;
+    use crate::server::subscribers::Subscribers;
+    use crate::target::driver::XilVitisNetP4Endian_XIL_VITIS_NET_P4_LITTLE_ENDIAN as XIL_VITIS_NET_P4_LITTLE_ENDIAN;
+    use crate::target::models::Device;
+    use crate::target::models::table::tests::insert_shadow;
+    use crate::target::models::table::tests::table;
+    use crate::utils::bits;
+    use p4runtime::google::rpc::Code as RpcCode;
+    use p4runtime::p4::v1::FieldMatch;
+    use p4runtime::p4::v1::MasterArbitrationUpdate;
+    use p4runtime::p4::v1::ReadResponse;
+    use p4runtime::p4::v1::StreamMessageResponse;
+    use p4runtime::p4::v1::TableEntry;
+    use p4runtime::p4::v1::Uint128;
+    use p4runtime::p4::v1::entity::Entity as EntityType;
+    use p4runtime::p4::v1::field_match::Exact;
+    use p4runtime::p4::v1::field_match::FieldMatchType;
+    use p4runtime::p4::v1::stream_message_response::Update as StreamMessageResponseUpdate;
+    use std::collections::HashMap;
+    use std::collections::HashSet;
+    use std::time::Instant;
+    use tokio::sync::RwLock;
+    use tokio::sync::mpsc;
+    use tonic::Code;
+    use tonic::Status;
+
+    type Sender = mpsc::Sender<Result<StreamMessageResponse, Status>>;
+    type Receiver = mpsc::Receiver<Result<StreamMessageResponse, Status>>;
+
+    fn arbitration(device_id: u64, election_id: u64) -> MasterArbitrationUpdate {
+        MasterArbitrationUpdate {
+            device_id: device_id,
+            election_id: Some(Uint128 { high: 0, low: election_id }),
+            ..Default::default()
+        }
+    }
+
+    fn stream() -> (Sender, Receiver) {
+        mpsc::channel(8)
+    }
+
+    fn next_arbitration_code(receiver: &mut Receiver) -> i32 {
+        match receiver.try_recv().unwrap().unwrap().update {
+            Some(StreamMessageResponseUpdate::Arbitration(update)) => update.status.unwrap().code,
+            update => panic!("Expected an arbitration update, got {:?}", update),
+        }
+    }
+
+    #[tokio::test]
+    async fn arbitrate_unknown_device_is_not_found() {
+        let devices: HashMap<u64, RwLock<Device>> = HashMap::new();
+        let mut connection = Connection::new(&devices);
+        let (sender, _receiver) = stream();
+
+        let status = P4RuntimeService::arbitrate(&devices, &mut connection, arbitration(1, 1), &sender).await.unwrap_err();
+
+        assert_eq!(status.code(), Code::NotFound);
+        assert_eq!(connection.get_device_id(), None);
+    }
+
+    #[tokio::test]
+    async fn arbitrate_duplicate_election_id_is_invalid_argument() {
+        let subscribers = RwLock::new(Subscribers::new(1));
+        let (first_sender, _first_receiver) = stream();
+        let (second_sender, _second_receiver) = stream();
+        let mut first = Connection::from_device_ids([1]);
+        let mut second = Connection::from_device_ids([1]);
+
+        P4RuntimeService::arbitrate_subscribers(&subscribers, &mut first, arbitration(1, 1), &first_sender).await.unwrap();
+        let status = P4RuntimeService::arbitrate_subscribers(&subscribers, &mut second, arbitration(1, 1), &second_sender).await.unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert!(!second.subscribed(1));
+        assert_eq!(subscribers.read().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn arbitrate_other_device_is_failed_precondition() {
+        let subscribers = RwLock::new(Subscribers::new(1));
+        let other_subscribers = RwLock::new(Subscribers::new(2));
+        let (sender, _receiver) = stream();
+        let mut connection = Connection::from_device_ids([1, 2]);
+
+        P4RuntimeService::arbitrate_subscribers(&subscribers, &mut connection, arbitration(1, 1), &sender).await.unwrap();
+        let status = P4RuntimeService::arbitrate_subscribers(&other_subscribers, &mut connection, arbitration(2, 1), &sender)
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::FailedPrecondition);
+        assert_eq!(connection.get_device_id(), Some(1));
+        assert!(other_subscribers.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn closing_primary_stream_promotes_backup() {
+        let subscribers = RwLock::new(Subscribers::new(1));
+        let (primary_sender, mut primary_receiver) = stream();
+        let (backup_sender, mut backup_receiver) = stream();
+        let mut primary = Connection::from_device_ids([1]);
+        let mut backup = Connection::from_device_ids([1]);
+
+        P4RuntimeService::arbitrate_subscribers(&subscribers, &mut primary, arbitration(1, 2), &primary_sender).await.unwrap();
+        P4RuntimeService::arbitrate_subscribers(&subscribers, &mut backup, arbitration(1, 1), &backup_sender).await.unwrap();
+        assert_eq!(next_arbitration_code(&mut primary_receiver), RpcCode::Ok as i32);
+        assert_eq!(next_arbitration_code(&mut backup_receiver), RpcCode::AlreadyExists as i32);
+
+        drop(primary_receiver);
+        P4RuntimeService::unsubscribe_subscribers(&subscribers, &primary, 1).await;
+
+        assert_eq!(next_arbitration_code(&mut backup_receiver), RpcCode::Ok as i32);
+        assert!(subscribers.read().await.is_primary("", backup.get_election_id(1)));
+    }
+
+    #[tokio::test]
+    async fn large_table_read_returns_every_entry_in_batches() {
+        const TABLE_ID: u32 = 0x02000001;
+        const ENTRY_COUNT: u32 = 100_000;
+        let mut large_table = table(TABLE_ID, &[("key", 32, "exact")], XIL_VITIS_NET_P4_LITTLE_ENDIAN);
+        for value in 0..ENTRY_COUNT {
+            let entry = TableEntry {
+                table_id: TABLE_ID,
+                r#match: vec![FieldMatch {
+                    field_id: 1,
+                    field_match_type: Some(FieldMatchType::Exact(Exact {
+                        value: bits::canonical(&value.to_be_bytes()),
+                    })),
+                }],
+                ..Default::default()
+            };
+            insert_shadow(&mut large_table, entry);
+        }
+        let tables = HashMap::from([(TABLE_ID, RwLock::new(large_table))]);
+
+        let start = Instant::now();
+        let entities = P4RuntimeService::read_table_entries(&tables, TableEntry::default()).await.unwrap();
+        let mut batch = ReadBatch::new(1000, 1024 * 1024);
+        let mut responses: Vec<ReadResponse> = entities.into_iter().filter_map(|entity| batch.push(entity)).collect();
+        responses.extend(batch.finish());
+        println!("Read {} table entries in {} responses in {:?}", ENTRY_COUNT, responses.len(), start.elapsed());
+
+        assert_eq!(responses.len(), (ENTRY_COUNT / 1000) as usize);
+        let values: HashSet<Vec<u8>> = responses
+            .into_iter()
+            .flat_map(|response| response.entities)
+            .map(|entity| match entity.entity {
+                Some(EntityType::TableEntry(table_entry)) => match table_entry.r#match[0].field_match_type.clone() {
+                    Some(FieldMatchType::Exact(exact)) => exact.value,
+                    field_match_type => panic!("Expected an exact match, got {:?}", field_match_type),
+                },
+                entity => panic!("Expected a table entry, got {:?}", entity),
+            })
+            .collect();
+        assert_eq!(values.len(), ENTRY_COUNT as usize);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::server::role_config::RoleConfig;
 use p4runtime::p4::v1::StreamMessageResponse;
 use p4runtime::p4::v1::Uint128;
 use tokio::sync::mpsc::Sender;
@@ -5,15 +6,34 @@ use tonic::Status;
 
 #[derive(Debug, Clone)]
 pub struct Subscriber {
+    pub role: String,
+    pub role_config: Option<RoleConfig>,
     pub election_id: Uint128,
     pub sender: Sender<Result<StreamMessageResponse, Status>>,
 }
 
 impl Subscriber {
-    pub fn new(election_id: Uint128, sender: Sender<Result<StreamMessageResponse, Status>>) -> Self {
+    pub fn new(role: String, role_config: Option<RoleConfig>, election_id: Uint128, sender: Sender<Result<StreamMessageResponse, Status>>) -> Self {
         Self {
+            role: role,
+            role_config: role_config,
             election_id: election_id,
             sender: sender,
         }
     }
+
+    /// The default role and roles without a config may access every entity.
+    pub fn allows(&self, p4_id: u32) -> bool {
+        match &self.role_config {
+            Some(role_config) if !self.role.is_empty() => role_config.allows(p4_id),
+            _ => true,
+        }
+    }
+
+    pub fn receives_packet_ins(&self) -> bool {
+        match &self.role_config {
+            Some(role_config) if !self.role.is_empty() => role_config.receives_packet_ins,
+            _ => true,
+        }
+    }
 }
@@ -1,9 +1,11 @@
+use crate::server::role_config::RoleConfig;
 use crate::server::subscriber::Subscriber;
 use crate::utils::p4runtime::as_u128_from;
 use crate::utils::p4runtime::as_uint128_from;
 use p4runtime::google::rpc::Code;
 use p4runtime::google::rpc::Status;
 use p4runtime::p4::v1::MasterArbitrationUpdate;
+use p4runtime::p4::v1::Role;
 use p4runtime::p4::v1::StreamMessageResponse;
 use p4runtime::p4::v1::Uint128;
 use p4runtime::p4::v1::stream_message_response::Update;
@@ -14,7 +16,7 @@ use tracing::warn;
 #[derive(Debug, Clone)]
 pub struct Subscribers {
     device_id: u64,
-    subscribers: HashMap<u128, Subscriber>,
+    subscribers: HashMap<(String, u128), Subscriber>,
 }
 
 impl Subscribers {
@@ -26,43 +28,79 @@ impl Subscribers {
     }
 
     pub fn insert(&mut self, subscriber: &Subscriber) {
-        let old_primary_id = self.get_primary_election_id();
+        let role = subscriber.role.clone();
+        let old_primary_id = self.get_primary_election_id(&role);
         let election_id = as_u128_from(&subscriber.election_id);
-        self.subscribers.insert(election_id, subscriber.clone());
-        let new_primary_id = self.get_primary_election_id();
+        self.subscribers.insert((role.clone(), election_id), subscriber.clone());
+        let new_primary_id = self.get_primary_election_id(&role);
         if old_primary_id == new_primary_id {
             self.notify_one(&subscriber.clone(), new_primary_id.unwrap());
         } else {
-            self.notify_all(new_primary_id.unwrap());
+            self.notify_all(&role, new_primary_id.unwrap());
         }
     }
 
-    pub fn delete(&mut self, election_id: Uint128) {
+    pub fn delete(&mut self, role: &str, election_id: Uint128) {
         let election_id: u128 = as_u128_from(&election_id);
-        let removed = self.subscribers.remove(&election_id).is_some();
+        let removed = self.subscribers.remove(&(role.to_string(), election_id)).is_some();
         if removed {
-            let new_primary_id = self.get_primary_election_id();
+            let new_primary_id = self.get_primary_election_id(role);
             if new_primary_id.is_some() {
-                self.notify_all(new_primary_id.unwrap());
+                self.notify_all(role, new_primary_id.unwrap());
             }
         }
     }
 
-    fn get_primary_election_id(&self) -> Option<u128> {
-        self.subscribers.keys().max().copied()
+    fn get_primary_election_id(&self, role: &str) -> Option<u128> {
+        self.subscribers
+            .keys()
+            .filter(|(subscriber_role, _)| subscriber_role == role)
+            .map(|(_, election_id)| *election_id)
+            .max()
     }
 
-    pub fn find(&self, election_id: Uint128) -> Option<&Subscriber> {
+    pub fn find(&self, role: &str, election_id: Uint128) -> Option<&Subscriber> {
         let election_id: u128 = as_u128_from(&election_id);
-        self.subscribers.get(&election_id)
+        self.subscribers.get(&(role.to_string(), election_id))
     }
-    pub fn find_mut(&mut self, election_id: Uint128) -> Option<&mut Subscriber> {
+    pub fn find_mut(&mut self, role: &str, election_id: Uint128) -> Option<&mut Subscriber> {
         let election_id: u128 = as_u128_from(&election_id);
-        self.subscribers.get_mut(&election_id)
+        self.subscribers.get_mut(&(role.to_string(), election_id))
     }
 
-    pub fn get_primary(&self) -> Option<&Subscriber> {
-        self.subscribers.values().max_by_key(|subscriber| as_u128_from(&subscriber.election_id))
+    pub fn get_primary(&self, role: &str) -> Option<&Subscriber> {
+        let election_id = self.get_primary_election_id(role)?;
+        self.subscribers.get(&(role.to_string(), election_id))
+    }
+
+    /// The config of `role`, taken from its primary when it has one.
+    pub fn get_role_config(&self, role: &str) -> Option<RoleConfig> {
+        if let Some(primary) = self.get_primary(role) {
+            return primary.role_config.clone();
+        }
+        self.subscribers
+            .values()
+            .filter(|subscriber| subscriber.role == role)
+            .find_map(|subscriber| subscriber.role_config.clone())
+    }
+
+    /// Returns another role and the first id it already holds exclusively among the exclusive ids of `role_config`.
+    pub fn find_exclusive_conflict(&self, role: &str, role_config: &RoleConfig) -> Option<(String, u32)> {
+        self.subscribers.values().filter(|subscriber| subscriber.role != role).find_map(|subscriber| {
+            let other_config = subscriber.role_config.as_ref()?;
+            role_config
+                .exclusive_p4_ids
+                .iter()
+                .find(|p4_id| other_config.exclusive_p4_ids.contains(p4_id))
+                .map(|p4_id| (subscriber.role.clone(), *p4_id))
+        })
+    }
+
+    pub fn is_primary(&self, role: &str, election_id: Option<Uint128>) -> bool {
+        match (self.get_primary_election_id(role), election_id) {
+            (Some(primary_election_id), Some(election_id)) => primary_election_id == as_u128_from(&election_id),
+            _ => false,
+        }
     }
 
     pub fn len(&self) -> usize {
@@ -77,8 +115,45 @@ impl Subscribers {
         self.subscribers.clear();
     }
 
-    fn notify_all(&self, primary_election_id: u128) {
-        for subscriber in self.subscribers.values() {
+    /// Sends `response` to the primary of every role allowed to see `p4_id`; `None` targets roles receiving packet-ins.
+    pub fn notify_primaries(&self, response: StreamMessageResponse, p4_id: Option<u32>) {
+        let mut roles: Vec<&String> = self.subscribers.keys().map(|(role, _)| role).collect();
+        roles.sort();
+        roles.dedup();
+        for role in roles {
+            let primary = self.get_primary(role).unwrap();
+            let allowed = match p4_id {
+                Some(p4_id) => primary.allows(p4_id),
+                None => primary.receives_packet_ins(),
+            };
+            if !allowed {
+                continue;
+            }
+            let election_id = as_u128_from(&primary.election_id);
+            match primary.sender.try_send(Ok(response.clone())) {
+                Ok(_) => {}
+                Err(tokio::sync::mpsc::error::TrySendError::Full(_)) => {
+                    warn!(
+                        election_id = %election_id,
+                        device_id = %self.device_id,
+                        role = %role,
+                        "Channel full for primary, message dropped"
+                    );
+                }
+                Err(tokio::sync::mpsc::error::TrySendError::Closed(_)) => {
+                    warn!(
+                        election_id = %election_id,
+                        device_id = %self.device_id,
+                        role = %role,
+                        "Channel closed for primary"
+                    );
+                }
+            }
+        }
+    }
+
+    fn notify_all(&self, role: &str, primary_election_id: u128) {
+        for subscriber in self.subscribers.values().filter(|subscriber| subscriber.role == role) {
             self.notify_one(subscriber, primary_election_id);
         }
     }
@@ -101,7 +176,13 @@ impl Subscribers {
 
         let update = MasterArbitrationUpdate {
             device_id: self.device_id,
-            role: None,
+            role: match subscriber.role.is_empty() {
+                true => None,
+                false => Some(Role {
+                    name: subscriber.role.clone(),
+                    ..Default::default()
+                }),
+            },
             election_id: Some(as_uint128_from(primary_election_id).clone()),
             status,
         };
@@ -137,8 +218,8 @@ impl Subscribers {
 }
 
 impl IntoIterator for Subscribers {
-    type Item = (u128, Subscriber);
-    type IntoIter = std::collections::hash_map::IntoIter<u128, Subscriber>;
+    type Item = ((String, u128), Subscriber);
+    type IntoIter = std::collections::hash_map::IntoIter<(String, u128), Subscriber>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.subscribers.into_iter()
@@ -146,10 +227,59 @@ impl IntoIterator for Subscribers {
 }
 
 impl<'a> IntoIterator for &'a Subscribers {
-    type Item = (&'a u128, &'a Subscriber);
-    type IntoIter = std::collections::hash_map::Iter<'a, u128, Subscriber>;
+    type Item = (&'a (String, u128), &'a Subscriber);
+    type IntoIter = std::collections::hash_map::Iter<'a, (String, u128), Subscriber>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.subscribers.iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Subscribers;
+    use crate::server::role_config::RoleConfig;
+    use crate::server::subscriber::Subscriber;
+    use p4runtime::p4::v1::Uint128;
+    use tokio::sync::mpsc;
+
+    fn subscriber(role: &str, role_config: Option<RoleConfig>, election_id: u64) -> Subscriber {
+        let (sender, _) = mpsc::channel(8);
+        Subscriber::new(role.to_string(), role_config, Uint128 { high: 0, low: election_id }, sender)
+    }
+
+    fn exclusive(p4_ids: Vec<u32>) -> RoleConfig {
+        RoleConfig {
+            exclusive_p4_ids: p4_ids,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn primaries_are_tracked_per_role() {
+        let mut subscribers = Subscribers::new(1);
+        subscribers.insert(&subscriber("", None, 3));
+        subscribers.insert(&subscriber("a", None, 1));
+        subscribers.insert(&subscriber("a", None, 2));
+
+        assert!(subscribers.is_primary("", Some(Uint128 { high: 0, low: 3 })));
+        assert!(subscribers.is_primary("a", Some(Uint128 { high: 0, low: 2 })));
+        assert!(!subscribers.is_primary("a", Some(Uint128 { high: 0, low: 1 })));
+
+        subscribers.delete("a", Uint128 { high: 0, low: 2 });
+        assert!(subscribers.is_primary("a", Some(Uint128 { high: 0, low: 1 })));
+        assert_eq!(subscribers.len(), 2);
+    }
+
+    #[test]
+    fn exclusive_ids_conflict_only_across_roles() {
+        let mut subscribers = Subscribers::new(1);
+        subscribers.insert(&subscriber("a", Some(exclusive(vec![0x02000001, 0x02000002])), 1));
+
+        assert_eq!(subscribers.find_exclusive_conflict("a", &exclusive(vec![0x02000001])), None);
+        assert_eq!(subscribers.find_exclusive_conflict("b", &exclusive(vec![0x02000003])), None);
+        assert_eq!(subscribers.find_exclusive_conflict("b", &exclusive(vec![0x02000003, 0x02000002])), Some(("a".to_string(), 0x02000002)));
+        assert_eq!(subscribers.get_role_config("a"), Some(exclusive(vec![0x02000001, 0x02000002])));
+        assert_eq!(subscribers.get_role_config("b"), None);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::server::error::WriteError;
+use p4runtime::google::rpc::Code;
+use p4runtime::google::rpc::Status as RpcStatus;
+use p4runtime::p4::v1::Error as P4RuntimeError;
+use pbjson_types::Any;
+use prost::Message;
+use std::collections::HashMap;
+use tonic::Status;
+
+#[derive(Debug, Clone, Default)]
+pub struct WriteErrors {
+    errors: Vec<P4RuntimeError>,
+    failed: bool,
+}
+
+impl WriteErrors {
+    const ERROR_TYPE_URL: &'static str = "type.googleapis.com/p4.v1.Error";
+    const DRIVER_SPACE: &'static str = "vitisnetp4";
+
+    pub fn new() -> Self {
+        Self { errors: Vec::new(), failed: false }
+    }
+
+    pub fn push(&mut self, result: Result<(), WriteError>) {
+        let error = match result {
+            Ok(()) => P4RuntimeError {
+                canonical_code: Code::Ok.into(),
+                message: "".to_string(),
+                space: "".to_string(),
+                code: 0,
+                details: None,
+            },
+            Err(e) => {
+                self.failed = true;
+                let driver_code = e.as_driver_code();
+                P4RuntimeError {
+                    canonical_code: e.as_rpc_code().into(),
+                    message: e.to_string(),
+                    space: driver_code.map(|_| Self::DRIVER_SPACE.to_string()).unwrap_or_default(),
+                    code: driver_code.map(|code| code as i32).unwrap_or(0),
+                    details: None,
+                }
+            }
+        };
+        self.errors.push(error);
+    }
+
+    /// Marks every applied update as rolled back, except those whose inverse failed, keyed by update index,
+    /// which are reported as INTERNAL because the hardware still holds their effect.
+    pub fn rollback(&mut self, failed_rollbacks: &HashMap<usize, String>) {
+        for (index, error) in self.errors.iter_mut().enumerate() {
+            if error.canonical_code != Code::Ok as i32 {
+                continue;
+            }
+            match failed_rollbacks.get(&index) {
+                Some(message) => {
+                    error.canonical_code = Code::Internal.into();
+                    error.message = format!("Failed to roll back after a previous error: {}", message);
+                }
+                None => {
+                    error.canonical_code = Code::Aborted.into();
+                    error.message = "Rolled back due to a previous error".to_string();
+                }
+            }
+        }
+    }
+
+    pub fn failed(&self) -> bool {
+        self.failed
+    }
+
+    pub fn into_result(self) -> Result<(), Status> {
+        if !self.failed {
+            return Ok(());
+        }
+
+        let message = "Write failure";
+        let status = RpcStatus {
+            code: Code::Unknown.into(),
+            message: message.to_string(),
+            details: self
+                .errors
+                .iter()
+                .map(|error| Any {
+                    type_url: Self::ERROR_TYPE_URL.to_string(),
+                    value: error.encode_to_vec().into(),
+                })
+                .collect(),
+        };
+        Err(Status::with_details(tonic::Code::Unknown, message, status.encode_to_vec().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WriteErrors;
+    use crate::server::error::WriteError;
+    use p4runtime::google::rpc::Code;
+    use std::collections::HashMap;
+
+    #[test]
+    fn rollback_reports_failed_inverses_as_internal() {
+        let mut errors = WriteErrors::new();
+        errors.push(Ok(()));
+        errors.push(Ok(()));
+        errors.push(Err(WriteError::NotSupportedEntity));
+
+        let mut failed_rollbacks: HashMap<usize, String> = HashMap::new();
+        failed_rollbacks.insert(1, "driver error".to_string());
+        errors.rollback(&failed_rollbacks);
+
+        let codes: Vec<i32> = errors.errors.iter().map(|error| error.canonical_code).collect();
+        assert_eq!(codes, vec![Code::Aborted as i32, Code::Internal as i32, Code::Unimplemented as i32]);
+        assert!(errors.errors[1].message.contains("driver error"));
+    }
+}
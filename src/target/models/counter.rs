@@ -0,0 +1,142 @@
+use super::error::CounterError;
+use crate::target::driver::XilVitisNetP4CounterComboRead;
+use crate::target::driver::XilVitisNetP4CounterComboWrite;
+use crate::target::driver::XilVitisNetP4CounterCtx;
+use crate::target::driver::XilVitisNetP4CounterReset;
+use crate::target::driver::XilVitisNetP4CounterSimpleRead;
+use crate::target::driver::XilVitisNetP4CounterSimpleWrite;
+use crate::target::driver::XilVitisNetP4CounterType_XIL_VITIS_NET_P4_COUNTER_BYTES as XIL_VITIS_NET_P4_COUNTER_BYTES;
+use crate::target::driver::XilVitisNetP4CounterType_XIL_VITIS_NET_P4_COUNTER_PACKETS_AND_BYTES as XIL_VITIS_NET_P4_COUNTER_PACKETS_AND_BYTES;
+use crate::target::driver::XilVitisNetP4ReturnType_XIL_VITIS_NET_P4_SUCCESS as XIL_VITIS_NET_P4_SUCCESS;
+use crate::target::driver::XilVitisNetP4TargetCtx;
+use crate::target::driver::XilVitisNetP4TargetGetCounterByName;
+use crate::target::schema::TargetCounterConfigSchema;
+use crate::utils::driver::code_to_name;
+use p4runtime::p4::v1::CounterData;
+use p4runtime::p4::v1::CounterEntry;
+use p4runtime::p4::v1::Index;
+use p4runtime::p4::v1::update::Type as UpdateType;
+use std::os::raw::c_char;
+use std::ptr::null_mut;
+
+#[derive(Debug, Clone)]
+pub struct Counter {
+    pub schema: TargetCounterConfigSchema,
+    pub counter_context_ptr: Option<*mut XilVitisNetP4CounterCtx>,
+}
+
+impl Counter {
+    pub fn new(schema: TargetCounterConfigSchema, target_context: *mut XilVitisNetP4TargetCtx) -> Result<Self, CounterError> {
+        let mut counter_context_ptr: *mut XilVitisNetP4CounterCtx = null_mut();
+        let code = unsafe {
+            XilVitisNetP4TargetGetCounterByName(
+                target_context,
+                schema.name_string.as_ptr() as *mut c_char,
+                &mut counter_context_ptr as *mut *mut XilVitisNetP4CounterCtx,
+            )
+        };
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            return Err(CounterError::Driver { name: code_to_name(code), code: code });
+        }
+        Ok(Self {
+            schema: schema,
+            counter_context_ptr: Some(counter_context_ptr),
+        })
+    }
+
+    pub fn apply(&mut self, entry: CounterEntry, update: UpdateType) -> Result<(), CounterError> {
+        match update {
+            UpdateType::Modify => self.modify(entry),
+            _ => Err(CounterError::InvalidArgument("Counter entry only supports MODIFY".to_string())),
+        }
+    }
+
+    pub fn modify(&mut self, entry: CounterEntry) -> Result<(), CounterError> {
+        let data = entry.data.unwrap_or_default();
+        match entry.index {
+            Some(index) => self.write_data(self.check_index(index.index)?, &data),
+            None if data.byte_count == 0 && data.packet_count == 0 => self.reset(),
+            None => {
+                for index in 0..self.schema.config.num_counters {
+                    self.write_data(index, &data)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub fn read(&self, filter: &CounterEntry) -> Result<Vec<CounterEntry>, CounterError> {
+        let indexes: Vec<u32> = match &filter.index {
+            Some(index) => vec![self.check_index(index.index)?],
+            None => (0..self.schema.config.num_counters).collect(),
+        };
+
+        let mut entries: Vec<CounterEntry> = Vec::with_capacity(indexes.len());
+        for index in indexes {
+            entries.push(CounterEntry {
+                counter_id: self.schema.id,
+                index: Some(Index { index: index as i64 }),
+                data: Some(self.read_data(index)?),
+            });
+        }
+        Ok(entries)
+    }
+
+    pub fn get_inverse(&self, entry: &CounterEntry) -> Result<Vec<CounterEntry>, CounterError> {
+        let mut filter = entry.clone();
+        filter.data = None;
+        self.read(&filter)
+    }
+
+    pub fn reset(&mut self) -> Result<(), CounterError> {
+        let code = unsafe { XilVitisNetP4CounterReset(self.counter_context_ptr.unwrap()) };
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            return Err(CounterError::Driver { name: code_to_name(code), code: code });
+        }
+        Ok(())
+    }
+
+    fn check_index(&self, index: i64) -> Result<u32, CounterError> {
+        if index < 0 || index >= self.schema.config.num_counters as i64 {
+            return Err(CounterError::OutOfRange(index));
+        }
+        Ok(index as u32)
+    }
+
+    fn read_data(&self, index: u32) -> Result<CounterData, CounterError> {
+        let mut packet_count: u64 = 0;
+        let mut byte_count: u64 = 0;
+        let code = match self.schema.config.counter_type {
+            XIL_VITIS_NET_P4_COUNTER_PACKETS_AND_BYTES => unsafe {
+                XilVitisNetP4CounterComboRead(self.counter_context_ptr.unwrap(), index, &mut packet_count as *mut u64, &mut byte_count as *mut u64)
+            },
+            XIL_VITIS_NET_P4_COUNTER_BYTES => unsafe { XilVitisNetP4CounterSimpleRead(self.counter_context_ptr.unwrap(), index, &mut byte_count as *mut u64) },
+            _ => unsafe { XilVitisNetP4CounterSimpleRead(self.counter_context_ptr.unwrap(), index, &mut packet_count as *mut u64) },
+        };
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            return Err(CounterError::Driver { name: code_to_name(code), code: code });
+        }
+        Ok(CounterData {
+            byte_count: byte_count as i64,
+            packet_count: packet_count as i64,
+        })
+    }
+
+    fn write_data(&mut self, index: u32, data: &CounterData) -> Result<(), CounterError> {
+        if data.byte_count < 0 || data.packet_count < 0 {
+            return Err(CounterError::InvalidArgument("Counter values must not be negative".to_string()));
+        }
+        let code = match self.schema.config.counter_type {
+            XIL_VITIS_NET_P4_COUNTER_PACKETS_AND_BYTES => unsafe { XilVitisNetP4CounterComboWrite(self.counter_context_ptr.unwrap(), index, data.packet_count as u64, data.byte_count as u64) },
+            XIL_VITIS_NET_P4_COUNTER_BYTES => unsafe { XilVitisNetP4CounterSimpleWrite(self.counter_context_ptr.unwrap(), index, data.byte_count as u64) },
+            _ => unsafe { XilVitisNetP4CounterSimpleWrite(self.counter_context_ptr.unwrap(), index, data.packet_count as u64) },
+        };
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            return Err(CounterError::Driver { name: code_to_name(code), code: code });
+        }
+        Ok(())
+    }
+}
+
+unsafe impl Send for Counter {}
+unsafe impl Sync for Counter {}
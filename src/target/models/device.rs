@@ -0,0 +1,283 @@
+use super::error::DeviceError;
+use crate::server::config::DeviceConfig;
+use crate::server::subscribers::Subscribers;
+use crate::target::driver::XilVitisNetP4EnvIf;
+use crate::target::driver::XilVitisNetP4ReturnType_XIL_VITIS_NET_P4_SUCCESS as XIL_VITIS_NET_P4_SUCCESS;
+use crate::target::driver::XilVitisNetP4TargetConfig;
+use crate::target::driver::XilVitisNetP4TargetCtx;
+use crate::target::driver::XilVitisNetP4TargetExit;
+use crate::target::driver::XilVitisNetP4TargetInit;
+use crate::target::models::Counter;
+use crate::target::models::Meter;
+use crate::target::models::PipelineConfig;
+use crate::target::models::PipelineError;
+use crate::target::models::Register;
+use crate::target::models::Table;
+use crate::target::schema::TargetConfigSchema;
+use crate::target::user_context::UserContext;
+use crate::target::user_context::user_log;
+use crate::target::user_context::user_word_read32;
+use crate::target::user_context::user_word_write32;
+use crate::utils::driver::code_to_name;
+use crate::utils::packet::PacketSocket;
+use p4runtime::p4::config::v1::P4Info;
+use p4runtime::p4::v1::ForwardingPipelineConfig;
+use p4runtime::p4::v1::TableEntry;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::warn;
+
+/// A table id with its entries and modified default entry.
+type TableSnapshot = (u32, Vec<TableEntry>, Option<TableEntry>);
+
+#[derive(Debug)]
+pub struct Device {
+    pub id: u64,
+    pub config: DeviceConfig,
+    pub tables: Arc<HashMap<u32, RwLock<Table>>>,
+    pub counters: Arc<HashMap<u32, RwLock<Counter>>>,
+    pub registers: Arc<HashMap<u32, RwLock<Register>>>,
+    pub meters: Arc<HashMap<u32, RwLock<Meter>>>,
+    pub cpu_port: Option<Arc<PacketSocket>>,
+    pub interface: XilVitisNetP4EnvIf,
+    pub target_schema: TargetConfigSchema,
+    pub target_config: XilVitisNetP4TargetConfig,
+    pub target_context: XilVitisNetP4TargetCtx,
+    pub subscribers: RwLock<Subscribers>,
+    pub pipeline_config: RwLock<PipelineConfig>,
+}
+
+impl Device {
+    pub fn open(config: DeviceConfig) -> Result<Self, DeviceError> {
+        let cpu_port = match config.cpu_port.is_empty() {
+            true => None,
+            false => Some(Arc::new(PacketSocket::open(&config.cpu_port)?)),
+        };
+        let target_schema = TargetConfigSchema::load_json(&config.target_config)?;
+        Device::build(config, target_schema, cpu_port)
+    }
+
+    /// Closes the driver and builds a device for `target_schema` on the same MMIO region.
+    /// The cpu port and subscribers move to the new device; on error they stay with `self`.
+    pub fn rebuild(&mut self, target_schema: TargetConfigSchema) -> Result<Self, DeviceError> {
+        self.close()?;
+        let mut device = Device::build(self.config.clone(), target_schema, self.cpu_port.clone())?;
+        std::mem::swap(&mut device.subscribers, &mut self.subscribers);
+        Ok(device)
+    }
+
+    fn build(config: DeviceConfig, mut target_schema: TargetConfigSchema, cpu_port: Option<Arc<PacketSocket>>) -> Result<Self, DeviceError> {
+        let mut interface = XilVitisNetP4EnvIf {
+            UserCtx: UserContext::new(config.mmio.clone())?.to_ptr(),
+            WordWrite32: Some(user_word_write32),
+            WordRead32: Some(user_word_read32),
+            LogError: Some(user_log),
+            LogInfo: Some(user_log),
+            DebugFlags: 0,
+        };
+        let mut target_config = target_schema.to_driver_config();
+        let mut target_context = XilVitisNetP4TargetCtx::default();
+        let code = unsafe {
+            XilVitisNetP4TargetInit(
+                &mut target_context as *mut XilVitisNetP4TargetCtx,
+                &mut interface as *mut XilVitisNetP4EnvIf,
+                &mut target_config as *mut XilVitisNetP4TargetConfig,
+            )
+        };
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            Device::free_user_context(&mut interface)?;
+            return Err(DeviceError::Driver { name: code_to_name(code), code: code });
+        }
+        let (tables, counters, registers) = match Device::build_externs(&config, &target_schema, &mut target_context) {
+            Ok(externs) => externs,
+            Err(e) => {
+                unsafe { XilVitisNetP4TargetExit(&mut target_context as *mut XilVitisNetP4TargetCtx) };
+                Device::free_user_context(&mut interface)?;
+                return Err(e);
+            }
+        };
+        let mut meters: HashMap<u32, RwLock<Meter>> = HashMap::new();
+        for meter_schema in target_schema.meter_list.iter() {
+            meters.insert(meter_schema.id, RwLock::new(Meter::new(meter_schema.clone())));
+        }
+        let pipeline_config = PipelineConfig::new(target_schema.as_p4info());
+        Ok(Self {
+            id: config.id,
+            interface: interface,
+            target_schema: target_schema,
+            target_config: target_config,
+            target_context: target_context,
+            tables: Arc::new(tables),
+            counters: Arc::new(counters),
+            registers: Arc::new(registers),
+            meters: Arc::new(meters),
+            cpu_port: cpu_port,
+            subscribers: RwLock::new(Subscribers::new(config.id)),
+            pipeline_config: RwLock::new(pipeline_config),
+            config: config,
+        })
+    }
+
+    /// Looks up the driver objects of every table, counter and register of an initialised target.
+    #[allow(clippy::type_complexity)]
+    fn build_externs(
+        config: &DeviceConfig,
+        target_schema: &TargetConfigSchema,
+        target_context: &mut XilVitisNetP4TargetCtx,
+    ) -> Result<(HashMap<u32, RwLock<Table>>, HashMap<u32, RwLock<Counter>>, HashMap<u32, RwLock<Register>>), DeviceError> {
+        let mut tables: HashMap<u32, RwLock<Table>> = HashMap::new();
+        for table_schema in target_schema.table_list.values() {
+            let table = Table::new(table_schema.clone(), target_context, config.read_mode)?;
+            tables.insert(table_schema.id, RwLock::new(table));
+        }
+        let mut counters: HashMap<u32, RwLock<Counter>> = HashMap::new();
+        for counter_schema in target_schema.counter_list.iter().filter(|counter_schema| counter_schema.direct_table_id.is_none()) {
+            let counter = Counter::new(counter_schema.clone(), target_context)?;
+            counters.insert(counter_schema.id, RwLock::new(counter));
+        }
+        let mut registers: HashMap<u32, RwLock<Register>> = HashMap::new();
+        for register_schema in target_schema.register_list.iter() {
+            let register = Register::new(register_schema.clone(), target_context, target_schema.endian)?;
+            registers.insert(register_schema.id, RwLock::new(register));
+        }
+        Ok((tables, counters, registers))
+    }
+
+    pub fn as_p4info(&self) -> P4Info {
+        self.target_schema.as_p4info()
+    }
+
+    /// Verifies against the target config carried in `p4_device_config`, or the device's own when it is empty.
+    pub fn verify(&self, config: &ForwardingPipelineConfig) -> Result<(), PipelineError> {
+        match config.p4_device_config.is_empty() {
+            true => PipelineConfig::verify(&self.as_p4info(), config),
+            false => PipelineConfig::verify(&TargetConfigSchema::load_json_slice(&config.p4_device_config)?.as_p4info(), config),
+        }
+    }
+
+    /// Re-initialises the driver for `config` by replacing this device with a freshly built one, using the
+    /// target config in `config.p4_device_config` when it differs from the committed one.
+    /// With `reconcile`, entries of tables unchanged since the last commit are written to the new device;
+    /// otherwise counters and registers are reset as well.
+    /// If the new device cannot be built or its state cannot be written, the previous target config,
+    /// its table entries and its pipeline config are restored. Should that fail as well, the device keeps
+    /// no table, counter or register, so nothing is left pointing into a closed driver context.
+    pub async fn commit(&mut self, config: ForwardingPipelineConfig, reconcile: bool) -> Result<(), PipelineError> {
+        let device_config_changed = self.pipeline_config.read().await.is_device_config_changed(&config);
+        let target_schema = match device_config_changed {
+            true => TargetConfigSchema::load_json_slice(&config.p4_device_config)?,
+            false => self.target_schema.clone(),
+        };
+        let previous_target_schema = self.target_schema.clone();
+        let previous_pipeline_config = self.pipeline_config.read().await.clone();
+        let previous_tables = self.snapshot_tables().await;
+        let preserved_tables: Vec<TableSnapshot> = match reconcile {
+            true => previous_tables
+                .iter()
+                .filter(|(table_id, _, _)| previous_pipeline_config.is_table_unchanged(*table_id, &config))
+                .cloned()
+                .collect(),
+            false => Vec::new(),
+        };
+
+        let result = match self.rebuild(target_schema) {
+            Ok(device) => {
+                *self = device;
+                match reconcile {
+                    true => self.restore_tables(preserved_tables).await,
+                    false => self.reset_externs().await,
+                }
+            }
+            Err(e) => Err(PipelineError::Device(e)),
+        };
+        if let Err(e) = result {
+            warn!("Failed to commit pipeline on device {}, restoring previous pipeline: {}", self.id, e);
+            match self.rebuild(previous_target_schema) {
+                Ok(device) => {
+                    *self = device;
+                    if let Err(restore_error) = self.restore_tables(previous_tables).await {
+                        warn!("Failed to restore table entries on device {}, dropping its externs: {}", self.id, restore_error);
+                        self.clear_externs();
+                    }
+                }
+                Err(rebuild_error) => {
+                    warn!("Failed to rebuild previous pipeline on device {}, dropping its externs: {}", self.id, rebuild_error);
+                    self.clear_externs();
+                }
+            }
+            *self.pipeline_config.write().await = previous_pipeline_config;
+            return Err(e);
+        }
+        self.pipeline_config.write().await.commit(config);
+        Ok(())
+    }
+
+    fn clear_externs(&mut self) {
+        self.tables = Arc::new(HashMap::new());
+        self.counters = Arc::new(HashMap::new());
+        self.registers = Arc::new(HashMap::new());
+    }
+
+    async fn reset_externs(&self) -> Result<(), PipelineError> {
+        for counter_lock in self.counters.values() {
+            counter_lock.write().await.reset()?;
+        }
+        for register_lock in self.registers.values() {
+            register_lock.write().await.reset()?;
+        }
+        Ok(())
+    }
+
+    async fn snapshot_tables(&self) -> Vec<TableSnapshot> {
+        let mut snapshots: Vec<TableSnapshot> = Vec::new();
+        for table_lock in self.tables.values() {
+            let table = table_lock.read().await;
+            snapshots.push((table.schema.id, table.entries.values().cloned().collect(), table.default_entry.clone()));
+        }
+        snapshots
+    }
+
+    async fn restore_tables(&self, snapshots: Vec<TableSnapshot>) -> Result<(), PipelineError> {
+        for (table_id, entries, default_entry) in snapshots {
+            let mut table = match self.tables.get(&table_id) {
+                Some(table_lock) => table_lock.write().await,
+                None => continue,
+            };
+            for entry in entries {
+                table.insert(entry)?;
+            }
+            if let Some(default_entry) = default_entry {
+                table.modify_default(default_entry)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn close(&mut self) -> Result<(), DeviceError> {
+        if self.interface.UserCtx.is_null() {
+            return Ok(());
+        }
+        unsafe { XilVitisNetP4TargetExit(&mut self.target_context as *mut XilVitisNetP4TargetCtx) };
+        Device::free_user_context(&mut self.interface)
+    }
+
+    fn free_user_context(interface: &mut XilVitisNetP4EnvIf) -> Result<(), DeviceError> {
+        let user_context = UserContext::from_ptr(interface.UserCtx);
+        user_context.mmio.close()?;
+        UserContext::free_ptr(interface.UserCtx);
+        interface.UserCtx = std::ptr::null_mut();
+        Ok(())
+    }
+}
+
+impl Drop for Device {
+    fn drop(&mut self) {
+        if let Err(e) = self.close() {
+            warn!("Error during device {} cleanup: {}", self.id, e);
+        }
+    }
+}
+
+unsafe impl Send for Device {}
+unsafe impl Sync for Device {}
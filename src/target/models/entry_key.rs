@@ -0,0 +1,16 @@
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EntryKey {
+    pub key: Vec<u8>,
+    pub mask: Vec<u8>,
+    pub priority: i32,
+}
+
+impl EntryKey {
+    pub fn new(key: Vec<u8>, mask: Vec<u8>, priority: i32) -> Self {
+        Self {
+            key: key,
+            mask: mask,
+            priority: priority,
+        }
+    }
+}
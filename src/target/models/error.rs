@@ -0,0 +1,195 @@
+use crate::target::driver::XilVitisNetP4ReturnType;
+use crate::utils::driver::name_to_rpc_code;
+use p4runtime::google::rpc::Code;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum TableError {
+    #[error("Failed Driver: {name}({code})")]
+    Driver { name: String, code: XilVitisNetP4ReturnType },
+
+    #[error("Not supported")]
+    NotSupported,
+
+    #[error("Not found action")]
+    NotFoundAction,
+
+    #[error("Not found key")]
+    NotFoundKey,
+
+    #[error("Already exists key")]
+    ExistsKey,
+
+    #[error("Not found match field")]
+    NotFoundMatchField,
+
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+}
+
+impl TableError {
+    pub fn as_rpc_code(&self) -> Code {
+        match self {
+            TableError::Driver { name, .. } => name_to_rpc_code(name),
+            TableError::NotSupported => Code::Unimplemented,
+            TableError::NotFoundAction => Code::InvalidArgument,
+            TableError::NotFoundKey => Code::NotFound,
+            TableError::ExistsKey => Code::AlreadyExists,
+            TableError::NotFoundMatchField => Code::InvalidArgument,
+            TableError::InvalidArgument(_) => Code::InvalidArgument,
+        }
+    }
+
+    pub fn as_driver_code(&self) -> Option<XilVitisNetP4ReturnType> {
+        match self {
+            TableError::Driver { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum CounterError {
+    #[error("Failed Driver: {name}({code})")]
+    Driver { name: String, code: XilVitisNetP4ReturnType },
+
+    #[error("Index {0} is out of range")]
+    OutOfRange(i64),
+
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+}
+
+impl CounterError {
+    pub fn as_rpc_code(&self) -> Code {
+        match self {
+            CounterError::Driver { name, .. } => name_to_rpc_code(name),
+            CounterError::OutOfRange(_) => Code::OutOfRange,
+            CounterError::InvalidArgument(_) => Code::InvalidArgument,
+        }
+    }
+
+    pub fn as_driver_code(&self) -> Option<XilVitisNetP4ReturnType> {
+        match self {
+            CounterError::Driver { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum RegisterError {
+    #[error("Failed Driver: {name}({code})")]
+    Driver { name: String, code: XilVitisNetP4ReturnType },
+
+    #[error("Index {0} is out of range")]
+    OutOfRange(i64),
+
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+}
+
+impl RegisterError {
+    pub fn as_rpc_code(&self) -> Code {
+        match self {
+            RegisterError::Driver { name, .. } => name_to_rpc_code(name),
+            RegisterError::OutOfRange(_) => Code::OutOfRange,
+            RegisterError::InvalidArgument(_) => Code::InvalidArgument,
+        }
+    }
+
+    pub fn as_driver_code(&self) -> Option<XilVitisNetP4ReturnType> {
+        match self {
+            RegisterError::Driver { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum MeterError {
+    #[error("Meter {0} is not backed by hardware")]
+    NotSupported(String),
+
+    #[error("Index {0} is out of range")]
+    OutOfRange(i64),
+
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+}
+
+impl MeterError {
+    pub fn as_rpc_code(&self) -> Code {
+        match self {
+            MeterError::NotSupported(_) => Code::Unimplemented,
+            MeterError::OutOfRange(_) => Code::OutOfRange,
+            MeterError::InvalidArgument(_) => Code::InvalidArgument,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum PipelineError {
+    #[error("Missing p4info")]
+    MissingP4Info,
+
+    #[error("P4Info does not match target: {0}")]
+    Mismatch(String),
+
+    #[error("No saved pipeline config")]
+    NotFoundSavedConfig,
+
+    #[error("Invalid p4_device_config: {0}")]
+    Json(#[from] crate::utils::serde::JsonError),
+
+    #[error("Failed to rebuild device: {0}")]
+    Device(#[from] DeviceError),
+
+    #[error("Failed to table: {0}")]
+    Table(#[from] TableError),
+
+    #[error("Failed to counter: {0}")]
+    Counter(#[from] CounterError),
+
+    #[error("Failed to register: {0}")]
+    Register(#[from] RegisterError),
+}
+
+impl PipelineError {
+    pub fn as_rpc_code(&self) -> Code {
+        match self {
+            PipelineError::MissingP4Info => Code::InvalidArgument,
+            PipelineError::Mismatch(_) => Code::InvalidArgument,
+            PipelineError::NotFoundSavedConfig => Code::FailedPrecondition,
+            PipelineError::Json(_) => Code::InvalidArgument,
+            PipelineError::Device(_) => Code::Internal,
+            PipelineError::Table(e) => e.as_rpc_code(),
+            PipelineError::Counter(e) => e.as_rpc_code(),
+            PipelineError::Register(e) => e.as_rpc_code(),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum DeviceError {
+    #[error("Failed to mmio")]
+    Mmio(#[from] crate::utils::mmio::MmioError),
+
+    #[error("Failed to driver [{name}({code})]")]
+    Driver { name: String, code: XilVitisNetP4ReturnType },
+
+    #[error("Failed to json")]
+    Json(#[from] crate::utils::serde::JsonError),
+
+    #[error("Failed to table: {0}")]
+    Table(#[from] TableError),
+
+    #[error("Failed to counter: {0}")]
+    Counter(#[from] CounterError),
+
+    #[error("Failed to register: {0}")]
+    Register(#[from] RegisterError),
+
+    #[error("Failed to cpu port")]
+    Packet(#[from] crate::utils::packet::PacketError),
+}
@@ -0,0 +1,73 @@
+use super::error::MeterError;
+use crate::target::schema::TargetMeterConfigSchema;
+use p4runtime::p4::v1::DirectMeterEntry;
+use p4runtime::p4::v1::MeterConfig;
+use p4runtime::p4::v1::MeterEntry;
+use p4runtime::p4::v1::update::Type as UpdateType;
+
+/// The Vitis Net P4 driver has no meter extern, so meters are advertised in
+/// P4Info but entry operations report UNIMPLEMENTED once they are validated.
+#[derive(Debug, Clone)]
+pub struct Meter {
+    pub schema: TargetMeterConfigSchema,
+}
+
+impl Meter {
+    pub fn new(schema: TargetMeterConfigSchema) -> Self {
+        Self { schema: schema }
+    }
+
+    pub fn apply(&mut self, entry: MeterEntry, update: UpdateType) -> Result<(), MeterError> {
+        if update != UpdateType::Modify {
+            return Err(MeterError::InvalidArgument("Meter entry only supports MODIFY".to_string()));
+        }
+        if let Some(index) = &entry.index {
+            self.check_index(index.index)?;
+        }
+        if let Some(config) = &entry.config {
+            Meter::check_config(config)?;
+        }
+        Err(MeterError::NotSupported(self.schema.name.clone()))
+    }
+
+    pub fn read(&self, filter: &MeterEntry) -> Result<Vec<MeterEntry>, MeterError> {
+        if let Some(index) = &filter.index {
+            self.check_index(index.index)?;
+        }
+        Err(MeterError::NotSupported(self.schema.name.clone()))
+    }
+
+    pub fn apply_direct(&mut self, entry: DirectMeterEntry, update: UpdateType) -> Result<(), MeterError> {
+        if update != UpdateType::Modify {
+            return Err(MeterError::InvalidArgument("Direct meter entry only supports MODIFY".to_string()));
+        }
+        if entry.table_entry.is_none() {
+            return Err(MeterError::InvalidArgument("Missing table entry".to_string()));
+        }
+        if let Some(config) = &entry.config {
+            Meter::check_config(config)?;
+        }
+        Err(MeterError::NotSupported(self.schema.name.clone()))
+    }
+
+    pub fn read_direct(&self, _filter: &DirectMeterEntry) -> Result<Vec<DirectMeterEntry>, MeterError> {
+        Err(MeterError::NotSupported(self.schema.name.clone()))
+    }
+
+    fn check_index(&self, index: i64) -> Result<(), MeterError> {
+        if index < 0 || index >= self.schema.size {
+            return Err(MeterError::OutOfRange(index));
+        }
+        Ok(())
+    }
+
+    fn check_config(config: &MeterConfig) -> Result<(), MeterError> {
+        if config.cir < 0 || config.cburst < 0 || config.pir < 0 || config.pburst < 0 {
+            return Err(MeterError::InvalidArgument("Meter rates and bursts must not be negative".to_string()));
+        }
+        if config.cir > config.pir {
+            return Err(MeterError::InvalidArgument("CIR must not exceed PIR".to_string()));
+        }
+        Ok(())
+    }
+}
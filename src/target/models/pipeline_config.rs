@@ -0,0 +1,289 @@
+use super::error::PipelineError;
+use p4runtime::p4::config::v1::Action;
+use p4runtime::p4::config::v1::P4Info;
+use p4runtime::p4::config::v1::Preamble;
+use p4runtime::p4::config::v1::Table;
+use p4runtime::p4::config::v1::match_field::Match as MatchType;
+use p4runtime::p4::v1::ForwardingPipelineConfig;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// The committed pipeline of a device and the one stored by VERIFY_AND_SAVE.
+#[derive(Debug, Clone)]
+pub struct PipelineConfig {
+    pub committed: ForwardingPipelineConfig,
+    pub saved: Option<ForwardingPipelineConfig>,
+}
+
+impl PipelineConfig {
+    pub fn new(p4info: P4Info) -> Self {
+        Self {
+            committed: ForwardingPipelineConfig {
+                p4info: Some(p4info),
+                p4_device_config: Vec::new(),
+                cookie: None,
+            },
+            saved: None,
+        }
+    }
+
+    pub fn save(&mut self, config: ForwardingPipelineConfig) {
+        self.saved = Some(config);
+    }
+
+    pub fn take_saved(&mut self) -> Result<ForwardingPipelineConfig, PipelineError> {
+        self.saved.take().ok_or(PipelineError::NotFoundSavedConfig)
+    }
+
+    pub fn commit(&mut self, config: ForwardingPipelineConfig) {
+        self.committed = config;
+    }
+
+    /// True when `config` carries a target config other than the committed one, which requires rebuilding the device.
+    pub fn is_device_config_changed(&self, config: &ForwardingPipelineConfig) -> bool {
+        !config.p4_device_config.is_empty() && config.p4_device_config != self.committed.p4_device_config
+    }
+
+    /// A table is unchanged when it keeps its match fields (id, bitwidth and match type) and the same set of
+    /// actions with the same params; names, annotations and other properties may differ.
+    pub fn is_table_unchanged(&self, table_id: u32, config: &ForwardingPipelineConfig) -> bool {
+        let (old_p4info, new_p4info) = match (&self.committed.p4info, &config.p4info) {
+            (Some(old_p4info), Some(new_p4info)) => (old_p4info, new_p4info),
+            _ => return false,
+        };
+        match (PipelineConfig::find_table(old_p4info, table_id), PipelineConfig::find_table(new_p4info, table_id)) {
+            (Some(old_table), Some(new_table)) => PipelineConfig::table_shape(old_p4info, old_table) == PipelineConfig::table_shape(new_p4info, new_table),
+            _ => false,
+        }
+    }
+
+    /// The match fields as (id, bitwidth, match type) and the actions as (id, params as (id, bitwidth)), all sorted by id.
+    #[allow(clippy::type_complexity)]
+    fn table_shape(p4info: &P4Info, table: &Table) -> (Vec<(u32, i32, Option<MatchType>)>, Vec<(u32, Option<Vec<(u32, i32)>>)>) {
+        let mut match_fields: Vec<(u32, i32, Option<MatchType>)> = table
+            .match_fields
+            .iter()
+            .map(|match_field| (match_field.id, match_field.bitwidth, match_field.r#match.clone()))
+            .collect();
+        match_fields.sort_by_key(|(id, _, _)| *id);
+        let mut actions: Vec<(u32, Option<Vec<(u32, i32)>>)> = table
+            .action_refs
+            .iter()
+            .map(|action_ref| {
+                let params = PipelineConfig::find_action(p4info, action_ref.id).map(|action| {
+                    let mut params: Vec<(u32, i32)> = action.params.iter().map(|param| (param.id, param.bitwidth)).collect();
+                    params.sort();
+                    params
+                });
+                (action_ref.id, params)
+            })
+            .collect();
+        actions.sort_by_key(|(id, _)| *id);
+        actions.dedup_by_key(|(id, _)| *id);
+        (match_fields, actions)
+    }
+
+    /// Checks that every object of the pushed P4Info exists on the target with the same shape, and that no table
+    /// or action of the target is missing from it.
+    pub fn verify(expected: &P4Info, config: &ForwardingPipelineConfig) -> Result<(), PipelineError> {
+        let p4info = config.p4info.as_ref().ok_or(PipelineError::MissingP4Info)?;
+
+        for table in p4info.tables.iter() {
+            let table_id = PipelineConfig::verify_preamble("table", table.preamble.as_ref(), expected.tables.iter().map(|table| table.preamble.as_ref()))?;
+            let expected_table = PipelineConfig::find_table(expected, table_id).unwrap();
+            if table.match_fields.len() != expected_table.match_fields.len() {
+                return Err(PipelineError::Mismatch(format!(
+                    "table {} has {} match fields, expected {}",
+                    table_id,
+                    table.match_fields.len(),
+                    expected_table.match_fields.len()
+                )));
+            }
+            for match_field in table.match_fields.iter() {
+                let expected_match_field = expected_table.match_fields.iter().find(|expected_match_field| expected_match_field.id == match_field.id);
+                match expected_match_field {
+                    Some(expected_match_field)
+                        if expected_match_field.name == match_field.name && expected_match_field.bitwidth == match_field.bitwidth && expected_match_field.r#match == match_field.r#match => {}
+                    _ => return Err(PipelineError::Mismatch(format!("match field {} ({}) of table {}", match_field.id, match_field.name, table_id))),
+                }
+            }
+            for action_ref in table.action_refs.iter() {
+                if !expected_table.action_refs.iter().any(|expected_action_ref| expected_action_ref.id == action_ref.id) {
+                    return Err(PipelineError::Mismatch(format!("action {} is not an action of table {}", action_ref.id, table_id)));
+                }
+            }
+            for expected_action_ref in expected_table.action_refs.iter() {
+                if !table.action_refs.iter().any(|action_ref| action_ref.id == expected_action_ref.id) {
+                    return Err(PipelineError::Mismatch(format!("action {} of table {} missing from pushed P4Info", expected_action_ref.id, table_id)));
+                }
+            }
+        }
+        PipelineConfig::verify_present(
+            "table",
+            p4info.tables.iter().map(|table| table.preamble.as_ref()),
+            expected.tables.iter().map(|table| table.preamble.as_ref()),
+        )?;
+
+        for action in p4info.actions.iter() {
+            let action_id = PipelineConfig::verify_preamble("action", action.preamble.as_ref(), expected.actions.iter().map(|action| action.preamble.as_ref()))?;
+            let expected_action = PipelineConfig::find_action(expected, action_id).unwrap();
+            if action.params.len() != expected_action.params.len() {
+                return Err(PipelineError::Mismatch(format!(
+                    "action {} has {} params, expected {}",
+                    action_id,
+                    action.params.len(),
+                    expected_action.params.len()
+                )));
+            }
+            for param in action.params.iter() {
+                let expected_param = expected_action.params.iter().find(|expected_param| expected_param.id == param.id);
+                match expected_param {
+                    Some(expected_param) if expected_param.name == param.name && expected_param.bitwidth == param.bitwidth => {}
+                    _ => return Err(PipelineError::Mismatch(format!("param {} ({}) of action {}", param.id, param.name, action_id))),
+                }
+            }
+        }
+        PipelineConfig::verify_present(
+            "action",
+            p4info.actions.iter().map(|action| action.preamble.as_ref()),
+            expected.actions.iter().map(|action| action.preamble.as_ref()),
+        )?;
+
+        for counter in p4info.counters.iter() {
+            PipelineConfig::verify_preamble("counter", counter.preamble.as_ref(), expected.counters.iter().map(|counter| counter.preamble.as_ref()))?;
+        }
+        for direct_counter in p4info.direct_counters.iter() {
+            PipelineConfig::verify_preamble(
+                "direct counter",
+                direct_counter.preamble.as_ref(),
+                expected.direct_counters.iter().map(|direct_counter| direct_counter.preamble.as_ref()),
+            )?;
+        }
+        for meter in p4info.meters.iter() {
+            PipelineConfig::verify_preamble("meter", meter.preamble.as_ref(), expected.meters.iter().map(|meter| meter.preamble.as_ref()))?;
+        }
+        for direct_meter in p4info.direct_meters.iter() {
+            PipelineConfig::verify_preamble(
+                "direct meter",
+                direct_meter.preamble.as_ref(),
+                expected.direct_meters.iter().map(|direct_meter| direct_meter.preamble.as_ref()),
+            )?;
+        }
+        for register in p4info.registers.iter() {
+            PipelineConfig::verify_preamble("register", register.preamble.as_ref(), expected.registers.iter().map(|register| register.preamble.as_ref()))?;
+        }
+        for digest in p4info.digests.iter() {
+            PipelineConfig::verify_preamble("digest", digest.preamble.as_ref(), expected.digests.iter().map(|digest| digest.preamble.as_ref()))?;
+        }
+        Ok(())
+    }
+
+    fn verify_preamble<'a>(kind: &str, preamble: Option<&Preamble>, expected_preambles: impl Iterator<Item = Option<&'a Preamble>>) -> Result<u32, PipelineError> {
+        let preamble = preamble.ok_or_else(|| PipelineError::Mismatch(format!("{} without preamble", kind)))?;
+        let expected_preambles: HashMap<u32, &Preamble> = expected_preambles.flatten().map(|expected_preamble| (expected_preamble.id, expected_preamble)).collect();
+        match expected_preambles.get(&preamble.id) {
+            Some(expected_preamble) if expected_preamble.name == preamble.name => Ok(preamble.id),
+            Some(expected_preamble) => Err(PipelineError::Mismatch(format!(
+                "{} {} is named {}, expected {}",
+                kind, preamble.id, preamble.name, expected_preamble.name
+            ))),
+            None => Err(PipelineError::Mismatch(format!("{} {} ({}) not found", kind, preamble.id, preamble.name))),
+        }
+    }
+
+    fn verify_present<'a, 'b>(kind: &str, preambles: impl Iterator<Item = Option<&'a Preamble>>, expected_preambles: impl Iterator<Item = Option<&'b Preamble>>) -> Result<(), PipelineError> {
+        let ids: HashSet<u32> = preambles.flatten().map(|preamble| preamble.id).collect();
+        match expected_preambles.flatten().find(|expected_preamble| !ids.contains(&expected_preamble.id)) {
+            Some(expected_preamble) => Err(PipelineError::Mismatch(format!(
+                "{} {} ({}) missing from pushed P4Info",
+                kind, expected_preamble.id, expected_preamble.name
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    fn find_table(p4info: &P4Info, table_id: u32) -> Option<&Table> {
+        p4info.tables.iter().find(|table| table.preamble.as_ref().is_some_and(|preamble| preamble.id == table_id))
+    }
+
+    fn find_action(p4info: &P4Info, action_id: u32) -> Option<&Action> {
+        p4info.actions.iter().find(|action| action.preamble.as_ref().is_some_and(|preamble| preamble.id == action_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PipelineConfig;
+    use crate::target::models::PipelineError;
+    use p4runtime::p4::config::v1::Action;
+    use p4runtime::p4::config::v1::ActionRef;
+    use p4runtime::p4::config::v1::P4Info;
+    use p4runtime::p4::config::v1::Preamble;
+    use p4runtime::p4::config::v1::Table;
+    use p4runtime::p4::v1::ForwardingPipelineConfig;
+
+    fn preamble(id: u32, name: &str) -> Option<Preamble> {
+        Some(Preamble {
+            id: id,
+            name: name.to_string(),
+            ..Default::default()
+        })
+    }
+
+    fn p4info(action_ids: &[u32]) -> P4Info {
+        P4Info {
+            tables: vec![Table {
+                preamble: preamble(0x02000001, "ingress.forward"),
+                action_refs: action_ids.iter().map(|&id| ActionRef { id: id, ..Default::default() }).collect(),
+                ..Default::default()
+            }],
+            actions: action_ids
+                .iter()
+                .map(|&id| Action {
+                    preamble: preamble(id, &format!("ingress.action_{}", id)),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn config(p4info: P4Info) -> ForwardingPipelineConfig {
+        ForwardingPipelineConfig {
+            p4info: Some(p4info),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn verify_accepts_the_target_p4info() {
+        let expected = p4info(&[0x01000001, 0x01000002]);
+        assert!(PipelineConfig::verify(&expected, &config(expected.clone())).is_ok());
+    }
+
+    #[test]
+    fn verify_rejects_missing_table() {
+        let expected = p4info(&[0x01000001]);
+        let mut pushed = expected.clone();
+        pushed.tables.clear();
+
+        let error = PipelineConfig::verify(&expected, &config(pushed)).unwrap_err();
+        assert!(matches!(error, PipelineError::Mismatch(message) if message.starts_with("table 33554433")));
+    }
+
+    #[test]
+    fn verify_rejects_missing_action() {
+        let expected = p4info(&[0x01000001, 0x01000002]);
+        let pushed = p4info(&[0x01000001]);
+
+        assert!(matches!(PipelineConfig::verify(&expected, &config(pushed)), Err(PipelineError::Mismatch(_))));
+    }
+
+    #[test]
+    fn verify_rejects_unknown_action() {
+        let expected = p4info(&[0x01000001]);
+        let pushed = p4info(&[0x01000001, 0x01000002]);
+
+        assert!(matches!(PipelineConfig::verify(&expected, &config(pushed)), Err(PipelineError::Mismatch(_))));
+    }
+}
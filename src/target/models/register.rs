@@ -0,0 +1,154 @@
+use super::error::RegisterError;
+use crate::target::driver::XilVitisNetP4Endian_XIL_VITIS_NET_P4_LITTLE_ENDIAN as XIL_VITIS_NET_P4_LITTLE_ENDIAN;
+use crate::target::driver::XilVitisNetP4RegisterCtx;
+use crate::target::driver::XilVitisNetP4RegisterRead;
+use crate::target::driver::XilVitisNetP4RegisterWrite;
+use crate::target::driver::XilVitisNetP4ReturnType_XIL_VITIS_NET_P4_SUCCESS as XIL_VITIS_NET_P4_SUCCESS;
+use crate::target::driver::XilVitisNetP4TargetCtx;
+use crate::target::driver::XilVitisNetP4TargetGetRegisterByName;
+use crate::target::schema::TargetRegisterConfigSchema;
+use crate::utils::bits;
+use crate::utils::driver::code_to_name;
+use p4runtime::p4::v1::Index;
+use p4runtime::p4::v1::P4Data;
+use p4runtime::p4::v1::RegisterEntry;
+use p4runtime::p4::v1::p4_data::Data as P4DataType;
+use p4runtime::p4::v1::update::Type as UpdateType;
+use std::os::raw::c_char;
+use std::ptr::null_mut;
+
+#[derive(Debug, Clone)]
+pub struct Register {
+    pub schema: TargetRegisterConfigSchema,
+    pub register_context_ptr: Option<*mut XilVitisNetP4RegisterCtx>,
+    endian: u32,
+}
+
+impl Register {
+    /// `endian` is the byte order of the target, which register buffers share with table keys and params.
+    pub fn new(schema: TargetRegisterConfigSchema, target_context: *mut XilVitisNetP4TargetCtx, endian: u32) -> Result<Self, RegisterError> {
+        let mut register_context_ptr: *mut XilVitisNetP4RegisterCtx = null_mut();
+        let code = unsafe {
+            XilVitisNetP4TargetGetRegisterByName(
+                target_context,
+                schema.name_string.as_ptr() as *mut c_char,
+                &mut register_context_ptr as *mut *mut XilVitisNetP4RegisterCtx,
+            )
+        };
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            return Err(RegisterError::Driver { name: code_to_name(code), code: code });
+        }
+        Ok(Self {
+            schema: schema,
+            register_context_ptr: Some(register_context_ptr),
+            endian: endian,
+        })
+    }
+
+    pub fn apply(&mut self, entry: RegisterEntry, update: UpdateType) -> Result<(), RegisterError> {
+        match update {
+            UpdateType::Modify => self.modify(entry),
+            _ => Err(RegisterError::InvalidArgument("Register entry only supports MODIFY".to_string())),
+        }
+    }
+
+    pub fn modify(&mut self, entry: RegisterEntry) -> Result<(), RegisterError> {
+        let value = match entry.data {
+            Some(data) => self.get_bitstring(data)?,
+            None if entry.index.is_none() => self.get_initial_value(),
+            None => return Err(RegisterError::InvalidArgument("Missing register data".to_string())),
+        };
+        match entry.index {
+            Some(index) => self.write_data(self.check_index(index.index)?, &value),
+            None => {
+                for index in 0..self.schema.config.get_size() {
+                    self.write_data(index, &value)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub fn reset(&mut self) -> Result<(), RegisterError> {
+        let value = self.get_initial_value();
+        for index in 0..self.schema.config.get_size() {
+            self.write_data(index, &value)?;
+        }
+        Ok(())
+    }
+
+    pub fn read(&self, filter: &RegisterEntry) -> Result<Vec<RegisterEntry>, RegisterError> {
+        let indexes: Vec<u32> = match &filter.index {
+            Some(index) => vec![self.check_index(index.index)?],
+            None => (0..self.schema.config.get_size()).collect(),
+        };
+
+        let mut entries: Vec<RegisterEntry> = Vec::with_capacity(indexes.len());
+        for index in indexes {
+            entries.push(RegisterEntry {
+                register_id: self.schema.id,
+                index: Some(Index { index: index as i64 }),
+                data: Some(P4Data {
+                    data: Some(P4DataType::Bitstring(bits::canonical(&self.read_data(index)?))),
+                }),
+            });
+        }
+        Ok(entries)
+    }
+
+    pub fn get_inverse(&self, entry: &RegisterEntry) -> Result<Vec<RegisterEntry>, RegisterError> {
+        let mut filter = entry.clone();
+        filter.data = None;
+        self.read(&filter)
+    }
+
+    fn get_bitstring(&self, data: P4Data) -> Result<Vec<u8>, RegisterError> {
+        let bitwidth = self.schema.config.data_size as usize;
+        match data.data {
+            Some(P4DataType::Bitstring(value)) => bits::fit(&value, bitwidth).ok_or(RegisterError::InvalidArgument(format!("Value does not fit in {} bits", bitwidth))),
+            _ => Err(RegisterError::InvalidArgument("Register data must be a bitstring".to_string())),
+        }
+    }
+
+    fn get_initial_value(&self) -> Vec<u8> {
+        let mut value = self.schema.config.get_initial_data();
+        if self.endian == XIL_VITIS_NET_P4_LITTLE_ENDIAN {
+            value.reverse();
+        }
+        value
+    }
+
+    fn check_index(&self, index: i64) -> Result<u32, RegisterError> {
+        if index < 0 || index >= self.schema.config.get_size() as i64 {
+            return Err(RegisterError::OutOfRange(index));
+        }
+        Ok(index as u32)
+    }
+
+    fn read_data(&self, index: u32) -> Result<Vec<u8>, RegisterError> {
+        let mut buffer = vec![0u8; bits::byte_width(self.schema.config.data_size as usize)];
+        let code = unsafe { XilVitisNetP4RegisterRead(self.register_context_ptr.unwrap(), index, buffer.as_mut_ptr()) };
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            return Err(RegisterError::Driver { name: code_to_name(code), code: code });
+        }
+        if self.endian == XIL_VITIS_NET_P4_LITTLE_ENDIAN {
+            buffer.reverse();
+        }
+        Ok(buffer)
+    }
+
+    fn write_data(&mut self, index: u32, value: &[u8]) -> Result<(), RegisterError> {
+        let mut buffer = bits::pad(value, bits::byte_width(self.schema.config.data_size as usize));
+        if self.endian == XIL_VITIS_NET_P4_LITTLE_ENDIAN {
+            buffer.reverse();
+        }
+        let code = unsafe { XilVitisNetP4RegisterWrite(self.register_context_ptr.unwrap(), index, buffer.as_mut_ptr()) };
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            return Err(RegisterError::Driver { name: code_to_name(code), code: code });
+        }
+        Ok(())
+    }
+}
+
+unsafe impl Send for Register {}
+unsafe impl Sync for Register {}
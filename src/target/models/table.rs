@@ -0,0 +1,816 @@
+use super::entry_key::EntryKey;
+use super::error::TableError;
+use crate::server::config::ReadMode;
+use crate::target::driver::XilVitisNetP4Endian_XIL_VITIS_NET_P4_LITTLE_ENDIAN as XIL_VITIS_NET_P4_LITTLE_ENDIAN;
+use crate::target::driver::XilVitisNetP4ReturnType_XIL_VITIS_NET_P4_SUCCESS as XIL_VITIS_NET_P4_SUCCESS;
+use crate::target::driver::XilVitisNetP4TableCtx;
+use crate::target::driver::XilVitisNetP4TableDelete;
+use crate::target::driver::XilVitisNetP4TableGetActionId;
+use crate::target::driver::XilVitisNetP4TableGetByKey;
+use crate::target::driver::XilVitisNetP4TableGetByResponse;
+use crate::target::driver::XilVitisNetP4TableInsert;
+use crate::target::driver::XilVitisNetP4TableSetDefaultAction;
+use crate::target::driver::XilVitisNetP4TableUpdate;
+use crate::target::driver::XilVitisNetP4TargetCtx;
+use crate::target::driver::XilVitisNetP4TargetGetTableByName;
+use crate::target::program::MatchType;
+use crate::target::schema::ActionSchema;
+use crate::target::schema::MatchFieldSchema;
+use crate::target::schema::TargetTableConfigSchema;
+use crate::utils::bits;
+use crate::utils::driver::code_to_name;
+use crate::utils::driver::name_to_rpc_code;
+use p4runtime::google::rpc::Code;
+use p4runtime::p4::v1::Action;
+use p4runtime::p4::v1::FieldMatch;
+use p4runtime::p4::v1::TableAction;
+use p4runtime::p4::v1::TableEntry;
+use p4runtime::p4::v1::action::Param;
+use p4runtime::p4::v1::field_match::Exact as FieldMatchExact;
+use p4runtime::p4::v1::field_match::FieldMatchType;
+use p4runtime::p4::v1::field_match::Lpm as FieldMatchLpm;
+use p4runtime::p4::v1::field_match::Range as FieldMatchRange;
+use p4runtime::p4::v1::field_match::Ternary as FieldMatchTernary;
+use p4runtime::p4::v1::update::Type as UpdateType;
+use std::collections::HashMap;
+use std::os::raw::c_char;
+use std::ptr::null_mut;
+
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub schema: TargetTableConfigSchema,
+    pub table_context_ptr: Option<*mut XilVitisNetP4TableCtx>,
+    pub entries: HashMap<EntryKey, TableEntry>,
+    pub default_entry: Option<TableEntry>,
+    pub read_mode: ReadMode,
+    action_ids: HashMap<u32, u32>,
+}
+
+impl Table {
+    pub fn new(schema: TargetTableConfigSchema, target_context: *mut XilVitisNetP4TargetCtx, read_mode: ReadMode) -> Result<Self, TableError> {
+        let mut table_context_ptr: *mut XilVitisNetP4TableCtx = null_mut();
+        let code = unsafe { XilVitisNetP4TargetGetTableByName(target_context, schema.name_string.as_ptr() as *mut c_char, &mut table_context_ptr as *mut *mut XilVitisNetP4TableCtx) };
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            return Err(TableError::Driver { name: code_to_name(code), code: code });
+        }
+        let mut action_ids: HashMap<u32, u32> = HashMap::new();
+        for action_schema in schema.config.action_list.values() {
+            let mut action_id: u32 = 0;
+            let code = unsafe { XilVitisNetP4TableGetActionId(table_context_ptr, action_schema.name_string.as_ptr() as *mut c_char, &mut action_id as *mut u32) };
+            if code != XIL_VITIS_NET_P4_SUCCESS {
+                return Err(TableError::Driver { name: code_to_name(code), code: code });
+            }
+            action_ids.insert(action_id, action_schema.id);
+        }
+        Ok(Self {
+            schema: schema,
+            table_context_ptr: Some(table_context_ptr),
+            entries: HashMap::new(),
+            default_entry: None,
+            read_mode: read_mode,
+            action_ids: action_ids,
+        })
+    }
+
+    pub fn apply(&mut self, entry: TableEntry, update: UpdateType) -> Result<(), TableError> {
+        if entry.is_default_action {
+            return match update {
+                UpdateType::Modify => self.modify_default(entry),
+                _ => Err(TableError::InvalidArgument("Default entry only supports MODIFY".to_string())),
+            };
+        }
+
+        match update {
+            UpdateType::Insert => self.insert(entry)?,
+            UpdateType::Modify => self.modify(entry)?,
+            UpdateType::Delete => self.delete(entry)?,
+            _ => return Err(TableError::NotSupported),
+        }
+
+        Ok(())
+    }
+
+    pub fn find(&self, entry: &TableEntry) -> Result<Option<&TableEntry>, TableError> {
+        let entry_key = self.build_entry_key(entry)?;
+        Ok(self.entries.get(&entry_key))
+    }
+
+    pub fn read(&self, filter: &TableEntry) -> Result<Vec<TableEntry>, TableError> {
+        if filter.is_default_action {
+            return Ok(vec![self.get_default_entry()]);
+        }
+
+        if !filter.r#match.is_empty() {
+            let entry = match self.read_mode {
+                ReadMode::Shadow => self.find(filter)?.cloned(),
+                ReadMode::Hardware => self.read_hardware_entry(filter)?,
+            };
+            return Ok(entry.filter(|entry| Table::matches_filter(entry, filter)).into_iter().collect());
+        }
+
+        Ok(self.entries.values().filter(|entry| Table::matches_filter(entry, filter)).cloned().collect())
+    }
+
+    /// The driver exposes no per-entry hit source, so no table supports idle timeouts.
+    fn check_idle_timeout(&self, entry: &TableEntry) -> Result<(), TableError> {
+        if entry.idle_timeout_ns != 0 {
+            return Err(TableError::InvalidArgument(format!("Table {} does not support idle timeout {}", self.schema.id, entry.idle_timeout_ns)));
+        }
+        Ok(())
+    }
+
+    pub fn needs_reconcile(&self, filter: &TableEntry) -> bool {
+        self.read_mode == ReadMode::Hardware && filter.r#match.is_empty() && !filter.is_default_action
+    }
+
+    pub fn reconcile(&mut self) -> Result<(), TableError> {
+        let key_width = bits::byte_width(self.schema.config.key_size_bits as usize);
+        let params_width = self.get_action_params_buffer_size();
+        let mut driver_action_ids: Vec<u32> = self.action_ids.keys().copied().collect();
+        driver_action_ids.sort();
+
+        let mut entries: HashMap<EntryKey, TableEntry> = HashMap::new();
+        for driver_action_id in driver_action_ids {
+            let mut position: u32 = 0;
+            while position < self.schema.config.cam_config.num_entries {
+                let mut key = vec![0u8; key_width];
+                let mut mask = vec![0u8; key_width];
+                let mut action_params = vec![0u8; params_width];
+                let mut action_params_mask = vec![0u8; params_width];
+                let code = unsafe {
+                    XilVitisNetP4TableGetByResponse(
+                        self.table_context_ptr.unwrap(),
+                        driver_action_id,
+                        action_params.as_mut_ptr(),
+                        action_params_mask.as_mut_ptr(),
+                        &mut position as *mut u32,
+                        key.as_mut_ptr(),
+                        mask.as_mut_ptr(),
+                    )
+                };
+                if code != XIL_VITIS_NET_P4_SUCCESS {
+                    let name = code_to_name(code);
+                    if name_to_rpc_code(&name) == Code::NotFound {
+                        break;
+                    }
+                    return Err(TableError::Driver { name: name, code: code });
+                }
+
+                if let Some((priority, action_id, action_params)) = self.get_by_key(&key, &mask)? {
+                    let mut entry = TableEntry {
+                        table_id: self.schema.id,
+                        r#match: self.decode_match(&key, &mask),
+                        priority: self.p4_priority(priority),
+                        ..Default::default()
+                    };
+                    let entry_key = self.build_entry_key(&entry)?;
+                    if let Some(previous) = self.entries.get(&entry_key) {
+                        entry = previous.clone();
+                    }
+                    entry.action = Some(self.decode_action(action_id, &action_params)?);
+                    entries.insert(entry_key, entry);
+                }
+                position += 1;
+            }
+        }
+
+        self.entries = entries;
+        Ok(())
+    }
+
+    fn read_hardware_entry(&self, filter: &TableEntry) -> Result<Option<TableEntry>, TableError> {
+        let entry_key = self.build_entry_key(filter)?;
+        self.cam_priority(entry_key.priority)?;
+
+        match self.get_by_key(&entry_key.key, &entry_key.mask)? {
+            Some((priority, action_id, action_params)) => Ok(Some(TableEntry {
+                table_id: self.schema.id,
+                r#match: filter.r#match.clone(),
+                priority: self.p4_priority(priority),
+                action: Some(self.decode_action(action_id, &action_params)?),
+                ..Default::default()
+            })),
+            None => Ok(None),
+        }
+    }
+
+    fn get_by_key(&self, key: &[u8], mask: &[u8]) -> Result<Option<(u32, u32, Vec<u8>)>, TableError> {
+        let mut priority: u32 = 0;
+        let mut action_id: u32 = 0;
+        let mut action_params = vec![0u8; self.get_action_params_buffer_size()];
+
+        let code = unsafe {
+            XilVitisNetP4TableGetByKey(
+                self.table_context_ptr.unwrap(),
+                key.as_ptr() as *mut u8,
+                mask.as_ptr() as *mut u8,
+                &mut priority as *mut u32,
+                &mut action_id as *mut u32,
+                action_params.as_mut_ptr(),
+            )
+        };
+
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            let name = code_to_name(code);
+            if name_to_rpc_code(&name) == Code::NotFound {
+                return Ok(None);
+            }
+            return Err(TableError::Driver { name: name, code: code });
+        }
+
+        Ok(Some((priority, action_id, action_params)))
+    }
+
+    fn decode_match(&self, key: &[u8], mask: &[u8]) -> Vec<FieldMatch> {
+        let mut key = key.to_vec();
+        let mut mask = mask.to_vec();
+        if self.schema.config.endian == XIL_VITIS_NET_P4_LITTLE_ENDIAN {
+            key.reverse();
+            mask.reverse();
+        }
+
+        let mut field_matches: Vec<FieldMatch> = Vec::new();
+        let mut offset = self.schema.match_field_list.get_total_bitwidth();
+        for match_field_schema in self.schema.match_field_list.get_ordered() {
+            let bitwidth = match_field_schema.bitwidth as usize;
+            offset -= bitwidth;
+            let value = bits::get_bits(&key, offset, bitwidth);
+            let field_mask = bits::get_bits(&mask, offset, bitwidth);
+            let field_match_type = match match_field_schema.match_type {
+                MatchType::Exact => Some(FieldMatchType::Exact(FieldMatchExact { value: bits::canonical(&value) })),
+                MatchType::Lpm => match bits::count_ones(&field_mask) {
+                    0 => None,
+                    prefix_len => Some(FieldMatchType::Lpm(FieldMatchLpm {
+                        value: bits::canonical(&value),
+                        prefix_len: prefix_len as i32,
+                    })),
+                },
+                MatchType::Ternary => match bits::count_ones(&field_mask) {
+                    0 => None,
+                    _ => Some(FieldMatchType::Ternary(FieldMatchTernary {
+                        value: bits::canonical(&value),
+                        mask: bits::canonical(&field_mask),
+                    })),
+                },
+                MatchType::Range => {
+                    if bits::count_ones(&value) == 0 && field_mask == bits::prefix_mask(bitwidth, bitwidth) {
+                        None
+                    } else {
+                        Some(FieldMatchType::Range(FieldMatchRange {
+                            low: bits::canonical(&value),
+                            high: bits::canonical(&field_mask),
+                        }))
+                    }
+                }
+            };
+            if let Some(field_match_type) = field_match_type {
+                field_matches.push(FieldMatch {
+                    field_id: match_field_schema.id,
+                    field_match_type: Some(field_match_type),
+                });
+            }
+        }
+        return field_matches;
+    }
+
+    fn decode_action(&self, driver_action_id: u32, action_params: &[u8]) -> Result<TableAction, TableError> {
+        let action_schema = match self.action_ids.get(&driver_action_id).and_then(|action_id| self.schema.config.action_list.get(action_id)) {
+            Some(action_schema) => action_schema,
+            _ => return Err(TableError::NotFoundAction),
+        };
+
+        let mut buffer = action_params[..bits::byte_width(self.get_action_params_size_bits()).max(1)].to_vec();
+        if self.schema.config.endian == XIL_VITIS_NET_P4_LITTLE_ENDIAN {
+            buffer.reverse();
+        }
+
+        let mut params: Vec<Param> = Vec::new();
+        let mut offset = action_schema.get_params_bitwidth();
+        for attribute_schema in action_schema.get_ordered_params() {
+            let bitwidth = attribute_schema.value as usize;
+            offset -= bitwidth;
+            params.push(Param {
+                param_id: attribute_schema.id,
+                value: bits::canonical(&bits::get_bits(&buffer, offset, bitwidth)),
+            });
+        }
+
+        Ok(TableAction {
+            r#type: Some(p4runtime::p4::v1::table_action::Type::Action(Action {
+                action_id: action_schema.id,
+                params: params,
+            })),
+        })
+    }
+
+    fn matches_filter(entry: &TableEntry, filter: &TableEntry) -> bool {
+        if filter.priority != 0 && entry.priority != filter.priority {
+            return false;
+        }
+        match Table::get_action_id(filter) {
+            Some(action_id) => Table::get_action_id(entry) == Some(action_id),
+            None => true,
+        }
+    }
+
+    fn get_action_id(entry: &TableEntry) -> Option<u32> {
+        match entry.action.as_ref().and_then(|table_action| table_action.r#type.as_ref()) {
+            Some(p4runtime::p4::v1::table_action::Type::Action(action)) => Some(action.action_id),
+            _ => None,
+        }
+    }
+
+    pub fn get_inverse(&self, entry: &TableEntry, update: UpdateType) -> Result<(TableEntry, UpdateType), TableError> {
+        if entry.is_default_action {
+            return Ok((self.get_default_entry(), UpdateType::Modify));
+        }
+
+        match update {
+            UpdateType::Insert => Ok((entry.clone(), UpdateType::Delete)),
+            UpdateType::Modify => Ok((self.find(entry)?.ok_or(TableError::NotFoundKey)?.clone(), UpdateType::Modify)),
+            UpdateType::Delete => Ok((self.find(entry)?.ok_or(TableError::NotFoundKey)?.clone(), UpdateType::Insert)),
+            _ => Err(TableError::NotSupported),
+        }
+    }
+
+    pub fn insert(&mut self, entry: TableEntry) -> Result<(), TableError> {
+        let entry_key = self.build_entry_key(&entry)?;
+        let priority = self.cam_priority(entry_key.priority)?;
+
+        if self.entries.contains_key(&entry_key) {
+            return Err(TableError::ExistsKey);
+        }
+        self.check_idle_timeout(&entry)?;
+
+        let (action_id, action_params) = self.build_action(&entry)?;
+
+        let code = unsafe {
+            XilVitisNetP4TableInsert(
+                self.table_context_ptr.unwrap(),
+                entry_key.key.as_ptr() as *mut u8,
+                entry_key.mask.as_ptr() as *mut u8,
+                priority,
+                action_id,
+                action_params.as_ptr() as *mut u8,
+            )
+        };
+
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            return Err(TableError::Driver { name: code_to_name(code), code: code });
+        }
+
+        self.entries.insert(entry_key, entry);
+        Ok(())
+    }
+
+    pub fn modify(&mut self, entry: TableEntry) -> Result<(), TableError> {
+        let entry_key = self.build_entry_key(&entry)?;
+        self.cam_priority(entry_key.priority)?;
+
+        if !self.entries.contains_key(&entry_key) {
+            return Err(TableError::NotFoundKey);
+        }
+        self.check_idle_timeout(&entry)?;
+
+        let (action_id, action_params) = self.build_action(&entry)?;
+
+        let code = unsafe {
+            XilVitisNetP4TableUpdate(
+                self.table_context_ptr.unwrap(),
+                entry_key.key.as_ptr() as *mut u8,
+                entry_key.mask.as_ptr() as *mut u8,
+                action_id,
+                action_params.as_ptr() as *mut u8,
+            )
+        };
+
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            return Err(TableError::Driver { name: code_to_name(code), code: code });
+        }
+
+        self.entries.insert(entry_key, entry);
+        Ok(())
+    }
+
+    pub fn modify_default(&mut self, entry: TableEntry) -> Result<(), TableError> {
+        if !entry.r#match.is_empty() || entry.priority != 0 {
+            return Err(TableError::InvalidArgument("Default entry must not have match fields or priority".to_string()));
+        }
+
+        let entry = match entry.action {
+            Some(_) => entry,
+            None => self.get_initial_default_entry(),
+        };
+
+        let (action_id, action_params) = self.build_action(&entry)?;
+
+        let code = unsafe { XilVitisNetP4TableSetDefaultAction(self.table_context_ptr.unwrap(), action_id, action_params.as_ptr() as *mut u8) };
+
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            return Err(TableError::Driver { name: code_to_name(code), code: code });
+        }
+
+        self.default_entry = Some(entry);
+        Ok(())
+    }
+
+    pub fn get_default_entry(&self) -> TableEntry {
+        match &self.default_entry {
+            Some(entry) => entry.clone(),
+            None => self.get_initial_default_entry(),
+        }
+    }
+
+    fn get_initial_default_entry(&self) -> TableEntry {
+        let params = self
+            .schema
+            .default_action_data
+            .iter()
+            .enumerate()
+            .map(|(index, value)| Param {
+                param_id: index as u32,
+                value: value.clone(),
+            })
+            .collect();
+        TableEntry {
+            table_id: self.schema.id,
+            action: Some(TableAction {
+                r#type: Some(p4runtime::p4::v1::table_action::Type::Action(Action {
+                    action_id: self.schema.default_action_id,
+                    params: params,
+                })),
+            }),
+            is_default_action: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn delete(&mut self, entry: TableEntry) -> Result<(), TableError> {
+        let entry_key = self.build_entry_key(&entry)?;
+        self.cam_priority(entry_key.priority)?;
+
+        if !self.entries.contains_key(&entry_key) {
+            return Err(TableError::NotFoundKey);
+        }
+
+        let code = unsafe { XilVitisNetP4TableDelete(self.table_context_ptr.unwrap(), entry_key.key.as_ptr() as *mut u8, entry_key.mask.as_ptr() as *mut u8) };
+
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            return Err(TableError::Driver { name: code_to_name(code), code: code });
+        }
+
+        self.entries.remove(&entry_key);
+
+        Ok(())
+    }
+
+    /// Removes every entry from the hardware and restores the initial default action.
+    pub fn clear(&mut self) -> Result<(), TableError> {
+        for entry_key in self.entries.keys() {
+            let code = unsafe { XilVitisNetP4TableDelete(self.table_context_ptr.unwrap(), entry_key.key.as_ptr() as *mut u8, entry_key.mask.as_ptr() as *mut u8) };
+            if code != XIL_VITIS_NET_P4_SUCCESS {
+                return Err(TableError::Driver { name: code_to_name(code), code: code });
+            }
+        }
+        self.entries.clear();
+
+        if self.default_entry.is_some() {
+            self.modify_default(self.get_initial_default_entry())?;
+            self.default_entry = None;
+        }
+        Ok(())
+    }
+
+    fn build_action(&self, entry: &TableEntry) -> Result<(u32, Vec<u8>), TableError> {
+        let table_action = match &entry.action {
+            Some(table_action) => table_action,
+            _ => return Err(TableError::InvalidArgument("Missing action".to_string())),
+        };
+
+        let action = match &table_action.r#type {
+            Some(p4runtime::p4::v1::table_action::Type::Action(action)) => action,
+            _ => return Err(TableError::NotSupported),
+        };
+
+        let action_schema = match self.schema.config.action_list.get(&action.action_id) {
+            Some(action_schema) => action_schema,
+            _ => return Err(TableError::NotFoundAction),
+        };
+
+        let mut action_id: u32 = 0;
+
+        let code = unsafe { XilVitisNetP4TableGetActionId(self.table_context_ptr.unwrap(), action_schema.name_string.as_ptr() as *mut c_char, &mut action_id as *mut u32) };
+
+        if code != XIL_VITIS_NET_P4_SUCCESS {
+            return Err(TableError::Driver { name: code_to_name(code), code: code });
+        }
+
+        Ok((action_id, self.build_action_params(action_schema, action)?))
+    }
+
+    fn build_action_params(&self, action_schema: &ActionSchema, action: &Action) -> Result<Vec<u8>, TableError> {
+        let mut params: HashMap<u32, &Param> = HashMap::new();
+        for param in action.params.iter() {
+            if !action_schema.param_list.contains_key(&param.param_id) {
+                return Err(TableError::InvalidArgument(format!("Unknown param {} for action {}", param.param_id, action.action_id)));
+            }
+            if params.insert(param.param_id, param).is_some() {
+                return Err(TableError::InvalidArgument(format!("Duplicate param {} for action {}", param.param_id, action.action_id)));
+            }
+        }
+
+        let size_bits = self.get_action_params_size_bits();
+        let mut offset = action_schema.get_params_bitwidth();
+        let mut buffer = vec![0u8; bits::byte_width(size_bits).max(1)];
+        for attribute_schema in action_schema.get_ordered_params() {
+            let bitwidth = attribute_schema.value as usize;
+            let param = match params.get(&attribute_schema.id) {
+                Some(param) => param,
+                _ => return Err(TableError::InvalidArgument(format!("Missing param {} for action {}", attribute_schema.id, action.action_id))),
+            };
+            let value = self.fit(&param.value, bitwidth)?;
+            offset -= bitwidth;
+            bits::set_bits(&mut buffer, offset, bitwidth, &value);
+        }
+
+        if self.schema.config.endian == XIL_VITIS_NET_P4_LITTLE_ENDIAN {
+            buffer.reverse();
+        }
+
+        Ok(buffer)
+    }
+
+    fn get_action_params_size_bits(&self) -> usize {
+        self.schema.config.action_list.values().map(|action_schema| action_schema.get_params_bitwidth()).max().unwrap_or(0)
+    }
+
+    fn get_action_params_buffer_size(&self) -> usize {
+        let size_bits = self.get_action_params_size_bits().max(self.schema.config.cam_config.response_size_bits as usize);
+        bits::byte_width(size_bits).max(1)
+    }
+
+    fn build_entry_key(&self, entry: &TableEntry) -> Result<EntryKey, TableError> {
+        let mut field_matches: HashMap<u32, &FieldMatch> = HashMap::new();
+        for field_match in entry.r#match.iter() {
+            if !self.schema.match_field_list.contains_key(&field_match.field_id) {
+                return Err(TableError::NotFoundMatchField);
+            }
+            if field_matches.insert(field_match.field_id, field_match).is_some() {
+                return Err(TableError::InvalidArgument(format!("Duplicate match field {}", field_match.field_id)));
+            }
+        }
+
+        let key_size_bits = self.schema.config.key_size_bits as usize;
+        let mut offset = self.schema.match_field_list.get_total_bitwidth();
+        if offset > key_size_bits {
+            return Err(TableError::NotSupported);
+        }
+
+        let mut key = vec![0u8; bits::byte_width(key_size_bits)];
+        let mut mask = vec![0u8; bits::byte_width(key_size_bits)];
+        for match_field_schema in self.schema.match_field_list.get_ordered() {
+            let bitwidth = match_field_schema.bitwidth as usize;
+            let (field_value, field_mask) = self.build_field(match_field_schema, field_matches.get(&match_field_schema.id).copied())?;
+            offset -= bitwidth;
+            bits::set_bits(&mut key, offset, bitwidth, &field_value);
+            bits::set_bits(&mut mask, offset, bitwidth, &field_mask);
+        }
+
+        if self.schema.config.endian == XIL_VITIS_NET_P4_LITTLE_ENDIAN {
+            key.reverse();
+            mask.reverse();
+        }
+
+        Ok(EntryKey::new(key, mask, entry.priority))
+    }
+
+    fn build_field(&self, schema: &MatchFieldSchema, field_match: Option<&FieldMatch>) -> Result<(Vec<u8>, Vec<u8>), TableError> {
+        let bitwidth = schema.bitwidth as usize;
+        let field_match = match field_match {
+            Some(field_match) => field_match,
+            None => {
+                return match schema.match_type {
+                    MatchType::Exact => Err(TableError::InvalidArgument(format!("Missing exact match field {}", schema.name))),
+                    MatchType::Range => Ok((vec![0u8; bits::byte_width(bitwidth)], bits::prefix_mask(bitwidth, bitwidth))),
+                    _ => Ok((vec![0u8; bits::byte_width(bitwidth)], vec![0u8; bits::byte_width(bitwidth)])),
+                };
+            }
+        };
+
+        let (value, mask) = match (&field_match.field_match_type, schema.match_type) {
+            (Some(FieldMatchType::Exact(field_match_exact)), MatchType::Exact) => (self.fit(&field_match_exact.value, bitwidth)?, bits::prefix_mask(bitwidth, bitwidth)),
+            (Some(FieldMatchType::Lpm(field_match_lpm)), MatchType::Lpm) => {
+                if field_match_lpm.prefix_len <= 0 || field_match_lpm.prefix_len as usize > bitwidth {
+                    return Err(TableError::InvalidArgument(format!(
+                        "Prefix length {} is out of range for {} bits",
+                        field_match_lpm.prefix_len, bitwidth
+                    )));
+                }
+                (self.fit(&field_match_lpm.value, bitwidth)?, bits::prefix_mask(field_match_lpm.prefix_len as usize, bitwidth))
+            }
+            (Some(FieldMatchType::Ternary(field_match_ternary)), MatchType::Ternary) => {
+                let mask = self.fit(&field_match_ternary.mask, bitwidth)?;
+                if mask.iter().all(|&b| b == 0) {
+                    return Err(TableError::InvalidArgument("Ternary mask must not be zero".to_string()));
+                }
+                (self.fit(&field_match_ternary.value, bitwidth)?, mask)
+            }
+            (Some(FieldMatchType::Range(field_match_range)), MatchType::Range) => {
+                if !self.supports_range() {
+                    return Err(TableError::NotSupported);
+                }
+                let low = self.fit(&field_match_range.low, bitwidth)?;
+                let high = self.fit(&field_match_range.high, bitwidth)?;
+                if low > high {
+                    return Err(TableError::InvalidArgument("Range low must not be greater than high".to_string()));
+                }
+                if low.iter().all(|&b| b == 0) && high == bits::prefix_mask(bitwidth, bitwidth) {
+                    return Err(TableError::InvalidArgument("Full range must be omitted as a don't care match".to_string()));
+                }
+                // Range fields carry the low bound in the key and the high bound in the mask.
+                return Ok((low, high));
+            }
+            _ => return Err(TableError::NotSupported),
+        };
+
+        if bits::apply_mask(&value, &mask) != value {
+            return Err(TableError::InvalidArgument("Value has bits set outside of the mask".to_string()));
+        }
+
+        Ok((value, mask))
+    }
+
+    fn fit(&self, value: &[u8], bitwidth: usize) -> Result<Vec<u8>, TableError> {
+        match bits::fit(value, bitwidth) {
+            Some(value) => Ok(value),
+            _ => Err(TableError::InvalidArgument(format!("Value does not fit in {} bits", bitwidth))),
+        }
+    }
+
+    fn supports_range(&self) -> bool {
+        self.schema.config.cam_config.get_format_fields().iter().any(|(_, kind)| *kind == 'r')
+    }
+
+    fn requires_priority(&self) -> bool {
+        self.schema.match_field_list.values().any(|field| matches!(field.match_type, MatchType::Ternary | MatchType::Range))
+    }
+
+    // P4Runtime gives precedence to the highest priority, the CAM to the lowest.
+    fn cam_priority(&self, priority: i32) -> Result<u32, TableError> {
+        if !self.requires_priority() {
+            if priority != 0 {
+                return Err(TableError::InvalidArgument(format!("Priority must be 0 for table {}", self.schema.name)));
+            }
+            return Ok(0);
+        }
+        let max_priority: u64 = (1u64 << self.schema.config.cam_config.priority_size_bits) - 1;
+        if priority <= 0 || priority as u64 > max_priority {
+            return Err(TableError::InvalidArgument(format!("Priority {} is out of range [1, {}]", priority, max_priority)));
+        }
+        Ok((max_priority - priority as u64) as u32)
+    }
+
+    fn p4_priority(&self, cam_priority: u32) -> i32 {
+        if !self.requires_priority() {
+            return 0;
+        }
+        let max_priority: u64 = (1u64 << self.schema.config.cam_config.priority_size_bits) - 1;
+        max_priority.saturating_sub(cam_priority as u64) as i32
+    }
+}
+
+unsafe impl Send for Table {}
+unsafe impl Sync for Table {}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::Table;
+    use super::XIL_VITIS_NET_P4_LITTLE_ENDIAN;
+    use crate::server::config::ReadMode;
+    use crate::target::driver::XilVitisNetP4Endian_XIL_VITIS_NET_P4_BIG_ENDIAN as XIL_VITIS_NET_P4_BIG_ENDIAN;
+    use crate::target::schema::TargetTableConfigSchema;
+    use p4runtime::p4::v1::FieldMatch;
+    use p4runtime::p4::v1::TableEntry;
+    use p4runtime::p4::v1::field_match::Exact as FieldMatchExact;
+    use p4runtime::p4::v1::field_match::FieldMatchType;
+    use p4runtime::p4::v1::field_match::Lpm as FieldMatchLpm;
+    use p4runtime::p4::v1::field_match::Ternary as FieldMatchTernary;
+    use serde_json::Value;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    /// Builds a table without a driver context, so only its shadow state can be used.
+    pub(crate) fn table(id: u32, match_fields: &[(&str, i32, &str)], endian: u32) -> Table {
+        let match_field_list: HashMap<String, Value> = match_fields
+            .iter()
+            .enumerate()
+            .map(|(index, (name, bitwidth, match_type))| {
+                let field_id = index as u32 + 1;
+                (field_id.to_string(), json!({ "ID": field_id, "Name": name, "BitWidth": bitwidth, "MatchType": match_type }))
+            })
+            .collect();
+        let key_size_bits: i32 = match_fields.iter().map(|(_, bitwidth, _)| bitwidth).sum();
+        let schema: TargetTableConfigSchema = serde_json::from_value(json!({
+            "ID": id,
+            "Name": "table",
+            "DefaultActionID": 0,
+            "MatchFieldList": match_field_list,
+            "NameString": "table",
+            "Config": {
+                "Endian": endian,
+                "Mode": 0,
+                "KeySizeBits": key_size_bits,
+                "CamConfig": {
+                    "BaseAddr": 0,
+                    "FormatString": "",
+                    "NumEntries": 0,
+                    "RamFrequencyHz": 0,
+                    "LookupFrequencyHz": 0,
+                    "LookupsPerSec": 0,
+                    "ResponseSizeBits": 0,
+                    "PrioritySizeBits": 8,
+                    "NumMasks": 0,
+                    "Endian": endian,
+                    "MemType": 0,
+                    "RamSizeKbytes": 0,
+                    "OptimizationType": 0,
+                    "RamChannelWidth": 0,
+                    "RamNumBanks": 0,
+                    "CamHWUpdateEnable": 0,
+                    "CamVariableRate": 0,
+                    "CamTplLookup": 0
+                },
+                "ActionIdWidthBits": 0,
+                "ActionListSize": 0,
+                "ActionList": {}
+            }
+        }))
+        .unwrap();
+        Table {
+            schema: schema,
+            table_context_ptr: None,
+            entries: HashMap::new(),
+            default_entry: None,
+            read_mode: ReadMode::Shadow,
+            action_ids: HashMap::new(),
+        }
+    }
+
+    /// Stores an entry in the shadow map without programming the driver.
+    pub(crate) fn insert_shadow(table: &mut Table, entry: TableEntry) {
+        let entry_key = table.build_entry_key(&entry).unwrap();
+        table.entries.insert(entry_key, entry);
+    }
+
+    fn field_match(field_id: u32, field_match_type: FieldMatchType) -> FieldMatch {
+        FieldMatch {
+            field_id: field_id,
+            field_match_type: Some(field_match_type),
+        }
+    }
+
+    fn mixed_table(endian: u32) -> Table {
+        table(0x02000001, &[("dst", 32, "exact"), ("prefix", 24, "lpm"), ("flags", 12, "ternary")], endian)
+    }
+
+    fn round_trip(table: &Table, r#match: Vec<FieldMatch>) -> Vec<FieldMatch> {
+        let entry = TableEntry {
+            table_id: table.schema.id,
+            r#match: r#match,
+            priority: 1,
+            ..Default::default()
+        };
+        let entry_key = table.build_entry_key(&entry).unwrap();
+        table.decode_match(&entry_key.key, &entry_key.mask)
+    }
+
+    #[test]
+    fn mixed_key_round_trips() {
+        let r#match = vec![
+            field_match(1, FieldMatchType::Exact(FieldMatchExact { value: vec![10, 0, 0, 1] })),
+            field_match(
+                2,
+                FieldMatchType::Lpm(FieldMatchLpm {
+                    value: vec![0xc0, 0xa8, 0x00],
+                    prefix_len: 16,
+                }),
+            ),
+            field_match(
+                3,
+                FieldMatchType::Ternary(FieldMatchTernary {
+                    value: vec![0x01, 0x20],
+                    mask: vec![0x0f, 0xf0],
+                }),
+            ),
+        ];
+        for endian in [XIL_VITIS_NET_P4_BIG_ENDIAN, XIL_VITIS_NET_P4_LITTLE_ENDIAN] {
+            assert_eq!(round_trip(&mixed_table(endian), r#match.clone()), r#match);
+        }
+    }
+
+    #[test]
+    fn mixed_key_round_trips_without_dont_care_fields() {
+        let r#match = vec![field_match(1, FieldMatchType::Exact(FieldMatchExact { value: vec![1] }))];
+        assert_eq!(round_trip(&mixed_table(XIL_VITIS_NET_P4_LITTLE_ENDIAN), r#match.clone()), r#match);
+    }
+}
@@ -0,0 +1,13 @@
+use crate::target::program::SourceInfo;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CounterArray {
+    pub id: u32,
+    pub name: String,
+    pub source_info: Option<SourceInfo>,
+    pub size: i64,
+    pub is_direct: bool,
+    pub binding: Option<String>,
+}
@@ -0,0 +1,26 @@
+use crate::utils::bits;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DefaultEntry {
+    pub action_id: u32,
+    pub action_const: Value,
+    pub action_data: Value,
+    pub action_entry_const: Value,
+}
+
+impl DefaultEntry {
+    pub fn get_action_data(&self) -> Vec<Vec<u8>> {
+        let mut action_data: Vec<Vec<u8>> = Vec::new();
+        if let Some(values) = self.action_data.as_array() {
+            for value in values.iter() {
+                if let Some(data) = value.as_str().and_then(bits::from_hex_string) {
+                    action_data.push(data);
+                }
+            }
+        }
+        return action_data;
+    }
+}
@@ -0,0 +1,50 @@
+use crate::target::program::Field;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeaderType {
+    pub id: u64,
+    pub name: String,
+    pub fields: Vec<Field>,
+    pub is_struct: Option<bool>,
+    pub length_exp: Option<String>,
+    pub max_length: Option<u64>,
+    #[serde(default)]
+    pub annotations: Value,
+}
+
+impl HeaderType {
+    pub fn get_field(&self, name: String) -> Option<Field> {
+        for field in self.fields.iter() {
+            if field.name == name {
+                return Some(field.clone());
+            }
+        }
+        return None;
+    }
+
+    /// Returns the `@controller_header` name, falling back to the
+    /// conventional `packet_in_header_t`/`packet_out_header_t` type names
+    /// because most program JSON backends drop header annotations.
+    pub fn get_controller_header(&self) -> Option<String> {
+        for annotation in self.annotations.as_array().into_iter().flatten() {
+            let annotation = match annotation {
+                Value::String(annotation) => annotation.clone(),
+                _ => annotation.to_string(),
+            };
+            if let Some(start) = annotation.find("controller_header") {
+                let quoted: Vec<&str> = annotation[start..].split('"').collect();
+                if quoted.len() >= 3 && !quoted[1].is_empty() {
+                    return Some(quoted[1].to_string());
+                }
+            }
+        }
+        match self.name.as_str() {
+            "packet_in_header_t" => Some("packet_in".to_string()),
+            "packet_out_header_t" => Some("packet_out".to_string()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::target::program::SourceInfo;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeterArray {
+    pub id: u32,
+    pub name: String,
+    pub source_info: Option<SourceInfo>,
+    pub is_direct: bool,
+    pub rate_count: u32,
+    pub size: i64,
+    pub binding: Option<String>,
+    pub result_target: Value,
+
+    #[serde(rename = "type")]
+    pub meter_type: String,
+}
@@ -0,0 +1,144 @@
+use crate::target::program::Actions;
+use crate::target::program::CounterArray;
+use crate::target::program::Header;
+use crate::target::program::HeaderType;
+use crate::target::program::MeterArray;
+use crate::target::program::Pipeline;
+use crate::target::program::RegisterArray;
+use crate::utils::serde::JsonError;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Program {
+    pub program: String,
+    pub __meta__: Value,
+    pub header_types: Vec<HeaderType>,
+    pub headers: Vec<Header>,
+    pub header_stacks: Value,
+    pub header_union_types: Value,
+    pub header_unions: Value,
+    pub header_union_stacks: Value,
+    pub field_lists: Value,
+    pub errors: Value,
+    pub enums: Value,
+    pub parsers: Value,
+    pub parse_vsets: Value,
+    pub deparsers: Value,
+    pub meter_arrays: Vec<MeterArray>,
+    pub counter_arrays: Vec<CounterArray>,
+    pub register_arrays: Vec<RegisterArray>,
+    pub calculations: Value,
+    pub learn_lists: Value,
+    pub actions: Actions,
+    pub pipelines: Vec<Pipeline>,
+    pub checksums: Value,
+    pub internet_checksums: Value,
+    pub force_arith: Value,
+    pub extern_instances: Value,
+    pub field_aliases: Value,
+    pub p4_design_id: Value,
+}
+
+impl Program {
+    pub fn load_json<P: AsRef<Path>>(path: P) -> Result<Self, JsonError> {
+        let path = path.as_ref();
+        let file = File::open(&path).map_err(|e| JsonError::FileRead { path: path.to_path_buf(), source: e })?;
+        let reader = BufReader::new(file);
+        let program: Self = serde_json::from_reader(reader)?;
+        Ok(program)
+    }
+
+    pub fn get_pipeline(&self, name: String) -> Option<Pipeline> {
+        for pipeline in self.pipelines.iter() {
+            if pipeline.name == name {
+                return Some(pipeline.clone());
+            }
+        }
+        return None;
+    }
+
+    pub fn get_ingress_pipeline(&self) -> Pipeline {
+        let pipeline = self.get_pipeline("ingress".to_string());
+        return pipeline.unwrap();
+    }
+
+    pub fn get_counter_array(&self, name: String) -> Option<CounterArray> {
+        for counter_array in self.counter_arrays.iter() {
+            if Program::matches_name(&counter_array.name, &name) {
+                return Some(counter_array.clone());
+            }
+        }
+        return None;
+    }
+
+    pub fn get_meter_array(&self, name: String) -> Option<MeterArray> {
+        for meter_array in self.meter_arrays.iter() {
+            if Program::matches_name(&meter_array.name, &name) {
+                return Some(meter_array.clone());
+            }
+        }
+        return None;
+    }
+
+    pub fn get_register_array(&self, name: String) -> Option<RegisterArray> {
+        for register_array in self.register_arrays.iter() {
+            if Program::matches_name(&register_array.name, &name) {
+                return Some(register_array.clone());
+            }
+        }
+        return None;
+    }
+
+    /// True when `name` is `driver_name` itself or ends with it after a `.`, so `cnt` matches
+    /// `MyIngress.cnt` but not `MyIngress.pkt_cnt`.
+    fn matches_name(name: &str, driver_name: &str) -> bool {
+        name == driver_name || name.strip_suffix(driver_name).is_some_and(|prefix| prefix.ends_with('.'))
+    }
+
+    pub fn get_header(&self, name: String) -> Option<Header> {
+        for header in self.headers.iter() {
+            if header.name == name {
+                return Some(header.clone());
+            }
+        }
+        return None;
+    }
+
+    pub fn get_controller_header_types(&self) -> Vec<(String, HeaderType)> {
+        let mut header_types: Vec<(String, HeaderType)> = Vec::new();
+        for header_type in self.header_types.iter() {
+            if let Some(name) = header_type.get_controller_header() {
+                header_types.push((name, header_type.clone()));
+            }
+        }
+        return header_types;
+    }
+
+    pub fn get_header_type(&self, name: String) -> Option<HeaderType> {
+        for header_type in self.header_types.iter() {
+            if header_type.name == name {
+                return Some(header_type.clone());
+            }
+        }
+        return None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Program;
+
+    #[test]
+    fn matches_name_at_dot_boundary() {
+        assert!(Program::matches_name("cnt", "cnt"));
+        assert!(Program::matches_name("MyIngress.cnt", "cnt"));
+        assert!(Program::matches_name("MyIngress.stats.cnt", "stats.cnt"));
+        assert!(!Program::matches_name("MyIngress.pkt_cnt", "cnt"));
+        assert!(!Program::matches_name("MyIngress.cnt2", "cnt"));
+    }
+}
@@ -0,0 +1,12 @@
+use crate::target::program::SourceInfo;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterArray {
+    pub id: u32,
+    pub name: String,
+    pub source_info: Option<SourceInfo>,
+    pub size: i64,
+    pub bitwidth: i32,
+}
@@ -0,0 +1,106 @@
+use crate::target::program::HeaderType;
+use crate::target::schema::ControllerHeaderFieldSchema;
+use crate::utils::bits;
+use crate::utils::packet::PacketError;
+use p4runtime::p4::v1::PacketMetadata;
+use serde::Deserialize;
+use serde::Serialize;
+
+use p4runtime::p4::config::v1::ControllerPacketMetadata as P4RuntimeControllerPacketMetadata;
+use p4runtime::p4::config::v1::Preamble as P4RuntimePreamble;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControllerHeaderSchema {
+    #[serde(rename = "ID")]
+    pub id: u32,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Fields")]
+    pub fields: Vec<ControllerHeaderFieldSchema>,
+}
+
+impl ControllerHeaderSchema {
+    pub fn from_program(name: String, header_type: HeaderType) -> Self {
+        Self {
+            id: header_type.id as u32,
+            name: name,
+            fields: header_type
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| ControllerHeaderFieldSchema {
+                    id: index as u32 + 1,
+                    name: field.name.clone(),
+                    bitwidth: field.bitwidth as u32,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn get_bitwidth(&self) -> usize {
+        self.fields.iter().map(|field| field.bitwidth as usize).sum()
+    }
+
+    pub fn get_byte_width(&self) -> usize {
+        bits::byte_width(self.get_bitwidth())
+    }
+
+    pub fn as_p4info(&self) -> P4RuntimeControllerPacketMetadata {
+        P4RuntimeControllerPacketMetadata {
+            preamble: Some(P4RuntimePreamble {
+                id: self.id,
+                name: self.name.clone(),
+                alias: "".to_string(),
+                annotations: Vec::new(),
+                annotation_locations: Vec::new(),
+                doc: None,
+                structured_annotations: Vec::new(),
+            }),
+            metadata: self.fields.iter().map(|field| field.as_p4info()).collect(),
+        }
+    }
+
+    /// Prepends the header built from `metadata` to `payload`; fields without metadata are zero.
+    pub fn encode(&self, metadata: &[PacketMetadata], payload: &[u8]) -> Result<Vec<u8>, PacketError> {
+        let mut header = vec![0u8; self.get_byte_width()];
+        let mut offset = header.len() * 8;
+        for field in self.fields.iter() {
+            let bitwidth = field.bitwidth as usize;
+            offset -= bitwidth;
+            if let Some(packet_metadata) = metadata.iter().find(|packet_metadata| packet_metadata.metadata_id == field.id) {
+                let value = bits::fit(&packet_metadata.value, bitwidth).ok_or(PacketError::InvalidMetadata {
+                    name: field.name.clone(),
+                    bitwidth: bitwidth,
+                })?;
+                bits::set_bits(&mut header, offset, bitwidth, &value);
+            }
+        }
+        for packet_metadata in metadata.iter() {
+            if !self.fields.iter().any(|field| field.id == packet_metadata.metadata_id) {
+                return Err(PacketError::NotFoundMetadata { id: packet_metadata.metadata_id });
+            }
+        }
+        header.extend_from_slice(payload);
+        Ok(header)
+    }
+
+    /// Splits `frame` into metadata and payload, or `None` if it is shorter than the header.
+    pub fn decode(&self, frame: &[u8]) -> Option<(Vec<PacketMetadata>, Vec<u8>)> {
+        let width = self.get_byte_width();
+        if frame.len() < width {
+            return None;
+        }
+        let header = &frame[..width];
+        let mut offset = width * 8;
+        let mut metadata: Vec<PacketMetadata> = Vec::with_capacity(self.fields.len());
+        for field in self.fields.iter() {
+            let bitwidth = field.bitwidth as usize;
+            offset -= bitwidth;
+            metadata.push(PacketMetadata {
+                metadata_id: field.id,
+                value: bits::canonical(&bits::get_bits(header, offset, bitwidth)),
+            });
+        }
+        Some((metadata, frame[width..].to_vec()))
+    }
+}
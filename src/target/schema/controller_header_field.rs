@@ -0,0 +1,28 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use p4runtime::p4::config::v1::controller_packet_metadata::Metadata as P4RuntimeMetadata;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControllerHeaderFieldSchema {
+    #[serde(rename = "ID")]
+    pub id: u32,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Bitwidth")]
+    pub bitwidth: u32,
+}
+
+impl ControllerHeaderFieldSchema {
+    pub fn as_p4info(&self) -> P4RuntimeMetadata {
+        P4RuntimeMetadata {
+            id: self.id,
+            name: self.name.clone(),
+            annotations: Vec::new(),
+            annotation_locations: Vec::new(),
+            bitwidth: self.bitwidth as i32,
+            type_name: None,
+            structured_annotations: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::target::driver::XilVitisNetP4CounterConfig;
+use crate::target::driver::XilVitisNetP4CounterType_XIL_VITIS_NET_P4_COUNTER_BYTES as XIL_VITIS_NET_P4_COUNTER_BYTES;
+use crate::target::driver::XilVitisNetP4CounterType_XIL_VITIS_NET_P4_COUNTER_PACKETS as XIL_VITIS_NET_P4_COUNTER_PACKETS;
+use crate::target::driver::XilVitisNetP4CounterType_XIL_VITIS_NET_P4_COUNTER_PACKETS_AND_BYTES as XIL_VITIS_NET_P4_COUNTER_PACKETS_AND_BYTES;
+use p4runtime::p4::config::v1::counter_spec::Unit as P4RuntimeCounterUnit;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CounterConfigSchema {
+    #[serde(rename = "BaseAddr")]
+    pub base_addr: usize,
+    #[serde(rename = "CounterType")]
+    pub counter_type: u32,
+    #[serde(rename = "NumCounters")]
+    pub num_counters: u32,
+    #[serde(rename = "Width")]
+    pub width: u32,
+}
+
+impl CounterConfigSchema {
+    pub fn from_driver_config(config: XilVitisNetP4CounterConfig) -> Self {
+        Self {
+            base_addr: config.BaseAddr,
+            counter_type: config.CounterType,
+            num_counters: config.NumCounters,
+            width: config.Width,
+        }
+    }
+
+    pub fn to_driver_config(&self) -> XilVitisNetP4CounterConfig {
+        XilVitisNetP4CounterConfig {
+            BaseAddr: self.base_addr,
+            CounterType: self.counter_type,
+            NumCounters: self.num_counters,
+            Width: self.width,
+        }
+    }
+
+    pub fn as_unit(&self) -> P4RuntimeCounterUnit {
+        match self.counter_type {
+            XIL_VITIS_NET_P4_COUNTER_PACKETS => P4RuntimeCounterUnit::Packets,
+            XIL_VITIS_NET_P4_COUNTER_BYTES => P4RuntimeCounterUnit::Bytes,
+            XIL_VITIS_NET_P4_COUNTER_PACKETS_AND_BYTES => P4RuntimeCounterUnit::Both,
+            _ => P4RuntimeCounterUnit::Unspecified,
+        }
+    }
+}
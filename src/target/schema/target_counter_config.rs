@@ -0,0 +1,79 @@
+use crate::target::driver::XilVitisNetP4TargetCounterConfig;
+use crate::target::program::Program;
+use crate::target::schema::CounterConfigSchema;
+use crate::utils::serde::cstring as serde_cstring;
+use serde::{Deserialize, Serialize};
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+use p4runtime::p4::config::v1::Counter as P4RuntimeCounter;
+use p4runtime::p4::config::v1::CounterSpec as P4RuntimeCounterSpec;
+use p4runtime::p4::config::v1::Preamble as P4RuntimePreamble;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetCounterConfigSchema {
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: u32,
+    #[serde(rename = "Name")]
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "DirectTableID")]
+    #[serde(default)]
+    pub direct_table_id: Option<u32>,
+    #[serde(rename = "NameString")]
+    #[serde(with = "serde_cstring")]
+    pub name_string: CString,
+    #[serde(rename = "Config")]
+    pub config: CounterConfigSchema,
+}
+
+impl TargetCounterConfigSchema {
+    pub fn from_driver_config(counter: XilVitisNetP4TargetCounterConfig, program: &Program) -> Self {
+        let name = TargetCounterConfigSchema::get_name(counter);
+        let counter_program = program.get_counter_array(name.to_string_lossy().to_string()).unwrap();
+        let direct_table_id = match (counter_program.is_direct, counter_program.binding) {
+            (true, Some(binding)) => program.get_ingress_pipeline().get_table(binding).map(|table| table.id),
+            _ => None,
+        };
+        Self {
+            id: counter_program.id,
+            name: counter_program.name,
+            direct_table_id: direct_table_id,
+            name_string: name,
+            config: CounterConfigSchema::from_driver_config(counter.Config),
+        }
+    }
+
+    pub fn get_name(counter: XilVitisNetP4TargetCounterConfig) -> CString {
+        unsafe {
+            let c_str = CStr::from_ptr(counter.NameStringPtr);
+            c_str.to_owned()
+        }
+    }
+
+    pub fn to_driver_config(&mut self) -> XilVitisNetP4TargetCounterConfig {
+        XilVitisNetP4TargetCounterConfig {
+            NameStringPtr: self.name_string.as_ptr() as *const c_char,
+            Config: self.config.to_driver_config(),
+        }
+    }
+
+    pub fn as_p4info(&self) -> P4RuntimeCounter {
+        P4RuntimeCounter {
+            preamble: Some(P4RuntimePreamble {
+                id: self.id,
+                name: self.name.clone(),
+                alias: "".to_string(),
+                annotations: Vec::new(),
+                annotation_locations: Vec::new(),
+                doc: None,
+                structured_annotations: Vec::new(),
+            }),
+            spec: Some(P4RuntimeCounterSpec { unit: self.config.as_unit().into() }),
+            size: self.config.num_counters as i64,
+            index_type_name: None,
+        }
+    }
+}
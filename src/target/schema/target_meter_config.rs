@@ -0,0 +1,83 @@
+use crate::target::program::MeterArray;
+use crate::target::program::Program;
+use serde::Deserialize;
+use serde::Serialize;
+
+use p4runtime::p4::config::v1::DirectMeter as P4RuntimeDirectMeter;
+use p4runtime::p4::config::v1::Meter as P4RuntimeMeter;
+use p4runtime::p4::config::v1::MeterSpec as P4RuntimeMeterSpec;
+use p4runtime::p4::config::v1::Preamble as P4RuntimePreamble;
+use p4runtime::p4::config::v1::meter_spec::Type as P4RuntimeMeterType;
+use p4runtime::p4::config::v1::meter_spec::Unit as P4RuntimeMeterUnit;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetMeterConfigSchema {
+    #[serde(rename = "ID")]
+    pub id: u32,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Unit")]
+    pub unit: String,
+    #[serde(rename = "Size")]
+    pub size: i64,
+    #[serde(rename = "DirectTableID")]
+    #[serde(default)]
+    pub direct_table_id: Option<u32>,
+}
+
+impl TargetMeterConfigSchema {
+    pub fn from_program(meter: MeterArray, program: &Program) -> Self {
+        let direct_table_id = match (meter.is_direct, meter.binding) {
+            (true, Some(binding)) => program.get_ingress_pipeline().get_table(binding).map(|table| table.id),
+            _ => None,
+        };
+        Self {
+            id: meter.id,
+            name: meter.name,
+            unit: meter.meter_type,
+            size: meter.size,
+            direct_table_id: direct_table_id,
+        }
+    }
+
+    pub fn as_spec(&self) -> P4RuntimeMeterSpec {
+        let unit = match self.unit.as_str() {
+            "bytes" => P4RuntimeMeterUnit::Bytes,
+            "packets" => P4RuntimeMeterUnit::Packets,
+            _ => P4RuntimeMeterUnit::Unspecified,
+        };
+        P4RuntimeMeterSpec {
+            unit: unit.into(),
+            r#type: P4RuntimeMeterType::TwoRateThreeColor.into(),
+        }
+    }
+
+    pub fn as_p4info(&self) -> P4RuntimeMeter {
+        P4RuntimeMeter {
+            preamble: Some(self.as_preamble()),
+            spec: Some(self.as_spec()),
+            size: self.size,
+            index_type_name: None,
+        }
+    }
+
+    pub fn as_direct_p4info(&self) -> P4RuntimeDirectMeter {
+        P4RuntimeDirectMeter {
+            preamble: Some(self.as_preamble()),
+            spec: Some(self.as_spec()),
+            direct_table_id: self.direct_table_id.unwrap_or_default(),
+        }
+    }
+
+    fn as_preamble(&self) -> P4RuntimePreamble {
+        P4RuntimePreamble {
+            id: self.id,
+            name: self.name.clone(),
+            alias: "".to_string(),
+            annotations: Vec::new(),
+            annotation_locations: Vec::new(),
+            doc: None,
+            structured_annotations: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::target::driver::XilVitisNetP4TargetRegisterConfig;
+use crate::target::program::Program;
+use crate::target::schema::RegisterConfigSchema;
+use crate::utils::serde::cstring as serde_cstring;
+use serde::{Deserialize, Serialize};
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+use p4runtime::p4::config::v1::P4BitTypeSpec as P4RuntimeBitTypeSpec;
+use p4runtime::p4::config::v1::P4BitstringLikeTypeSpec as P4RuntimeBitstringLikeTypeSpec;
+use p4runtime::p4::config::v1::P4DataTypeSpec as P4RuntimeDataTypeSpec;
+use p4runtime::p4::config::v1::Preamble as P4RuntimePreamble;
+use p4runtime::p4::config::v1::Register as P4RuntimeRegister;
+use p4runtime::p4::config::v1::p4_bitstring_like_type_spec::TypeSpec as P4RuntimeBitstringLikeType;
+use p4runtime::p4::config::v1::p4_data_type_spec::TypeSpec as P4RuntimeDataType;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetRegisterConfigSchema {
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: u32,
+    #[serde(rename = "Name")]
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "NameString")]
+    #[serde(with = "serde_cstring")]
+    pub name_string: CString,
+    #[serde(rename = "Config")]
+    pub config: RegisterConfigSchema,
+}
+
+impl TargetRegisterConfigSchema {
+    pub fn from_driver_config(register: XilVitisNetP4TargetRegisterConfig, program: &Program) -> Self {
+        let name = TargetRegisterConfigSchema::get_name(register);
+        let register_program = program.get_register_array(name.to_string_lossy().to_string()).unwrap();
+        Self {
+            id: register_program.id,
+            name: register_program.name,
+            name_string: name,
+            config: RegisterConfigSchema::from_driver_config(register.Config),
+        }
+    }
+
+    pub fn get_name(register: XilVitisNetP4TargetRegisterConfig) -> CString {
+        unsafe {
+            let c_str = CStr::from_ptr(register.NameStringPtr);
+            c_str.to_owned()
+        }
+    }
+
+    pub fn to_driver_config(&mut self) -> XilVitisNetP4TargetRegisterConfig {
+        XilVitisNetP4TargetRegisterConfig {
+            NameStringPtr: self.name_string.as_ptr() as *const c_char,
+            Config: self.config.to_driver_config(),
+        }
+    }
+
+    pub fn as_p4info(&self) -> P4RuntimeRegister {
+        P4RuntimeRegister {
+            preamble: Some(P4RuntimePreamble {
+                id: self.id,
+                name: self.name.clone(),
+                alias: "".to_string(),
+                annotations: Vec::new(),
+                annotation_locations: Vec::new(),
+                doc: None,
+                structured_annotations: Vec::new(),
+            }),
+            type_spec: Some(P4RuntimeDataTypeSpec {
+                type_spec: Some(P4RuntimeDataType::Bitstring(P4RuntimeBitstringLikeTypeSpec {
+                    type_spec: Some(P4RuntimeBitstringLikeType::Bit(P4RuntimeBitTypeSpec {
+                        bitwidth: self.config.data_size as i32,
+                    })),
+                    annotations: Vec::new(),
+                    annotation_locations: Vec::new(),
+                    structured_annotations: Vec::new(),
+                })),
+            }),
+            size: self.config.get_size() as i32,
+            index_type_name: None,
+        }
+    }
+}
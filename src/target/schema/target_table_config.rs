@@ -0,0 +1,141 @@
+use crate::target::driver::XilVitisNetP4TargetTableConfig;
+use crate::target::program::Program;
+use crate::target::schema::MatchFieldsSchema;
+use crate::target::schema::TableConfigSchema;
+use crate::utils::p4runtime::as_p4_id;
+use crate::utils::serde::cstring as serde_cstring;
+use p4runtime::p4::config::v1::p4_ids::Prefix;
+use serde::Deserialize;
+use serde::Serialize;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+use p4runtime::p4::config::v1::ActionRef as P4RuntimeActionRef;
+use p4runtime::p4::config::v1::Preamble as P4RuntimePreamble;
+use p4runtime::p4::config::v1::Table as P4RuntimeTable;
+use p4runtime::p4::config::v1::TableActionCall as P4RuntimeTableActionCall;
+use p4runtime::p4::config::v1::action_ref::Scope;
+use p4runtime::p4::config::v1::table::IdleTimeoutBehavior;
+use p4runtime::p4::config::v1::table_action_call::Argument as P4RuntimeArgument;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetTableConfigSchema {
+    #[serde(rename = "ID")]
+    pub id: u32,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "DefaultActionID")]
+    pub default_action_id: u32,
+    #[serde(rename = "DefaultActionData")]
+    #[serde(default)]
+    pub default_action_data: Vec<Vec<u8>>,
+    #[serde(rename = "DirectMeterIDs")]
+    #[serde(default)]
+    pub direct_meter_ids: Vec<u32>,
+    #[serde(rename = "MatchFieldList")]
+    pub match_field_list: MatchFieldsSchema,
+    #[serde(rename = "NameString")]
+    #[serde(with = "serde_cstring")]
+    pub name_string: CString,
+    #[serde(rename = "Config")]
+    pub config: TableConfigSchema,
+}
+
+impl TargetTableConfigSchema {
+    pub fn from_driver_config(table: XilVitisNetP4TargetTableConfig, program: &Program) -> Self {
+        let name = TargetTableConfigSchema::get_name(table);
+        let pipeline_program = program.get_ingress_pipeline();
+        let table_program = pipeline_program.get_table(name.to_string_lossy().to_string()).unwrap();
+        let direct_meter_ids = match &table_program.direct_meters {
+            Some(meter_name) => program.get_meter_array(meter_name.clone()).iter().map(|meter_array| meter_array.id).collect(),
+            None => Vec::new(),
+        };
+        Self {
+            id: table_program.id,
+            name: table_program.name,
+            default_action_id: table_program.default_entry.action_id,
+            default_action_data: table_program.default_entry.get_action_data(),
+            direct_meter_ids: direct_meter_ids,
+            match_field_list: table_program.match_fields.as_schema(program),
+            name_string: name,
+            config: TableConfigSchema::from_driver_config(table.Config, program.actions.as_schema()),
+        }
+    }
+
+    /// Moves the table, its actions and its direct resources into their P4Info id spaces.
+    pub fn assign_p4_ids(&mut self) {
+        self.id = as_p4_id(Prefix::Table, self.id);
+        self.default_action_id = as_p4_id(Prefix::Action, self.default_action_id);
+        self.direct_meter_ids = self.direct_meter_ids.iter().map(|id| as_p4_id(Prefix::DirectMeter, *id)).collect();
+        self.config.action_list = std::mem::take(&mut self.config.action_list)
+            .into_values()
+            .map(|mut action| {
+                action.id = as_p4_id(Prefix::Action, action.id);
+                (action.id, action)
+            })
+            .collect();
+    }
+
+    pub fn get_name(table: XilVitisNetP4TargetTableConfig) -> CString {
+        unsafe {
+            let c_str = CStr::from_ptr(table.NameStringPtr);
+            c_str.to_owned()
+        }
+    }
+
+    pub fn to_driver_config(&mut self) -> XilVitisNetP4TargetTableConfig {
+        let config = XilVitisNetP4TargetTableConfig {
+            NameStringPtr: self.name_string.as_ptr() as *const c_char,
+            Config: self.config.to_driver_config(),
+        };
+        return config;
+    }
+
+    pub fn as_p4info(&self) -> P4RuntimeTable {
+        let mut action_ids: Vec<u32> = self.config.action_list.keys().copied().collect();
+        action_ids.sort();
+        P4RuntimeTable {
+            preamble: Some(P4RuntimePreamble {
+                id: self.id,
+                name: self.name.clone(),
+                alias: "".to_string(),
+                annotations: Vec::new(),
+                annotation_locations: Vec::new(),
+                doc: None,
+                structured_annotations: Vec::new(),
+            }),
+            match_fields: self.match_field_list.as_p4info(),
+            action_refs: action_ids
+                .into_iter()
+                .map(|id| P4RuntimeActionRef {
+                    id: id,
+                    scope: Scope::TableAndDefault.into(),
+                    annotations: Vec::new(),
+                    annotation_locations: Vec::new(),
+                    structured_annotations: Vec::new(),
+                })
+                .collect(),
+            const_default_action_id: 0,
+            initial_default_action: Some(P4RuntimeTableActionCall {
+                action_id: self.default_action_id,
+                arguments: self
+                    .default_action_data
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| P4RuntimeArgument {
+                        param_id: index as u32,
+                        value: value.clone(),
+                    })
+                    .collect(),
+            }),
+            implementation_id: 0,
+            direct_resource_ids: self.direct_meter_ids.clone(),
+            size: self.config.cam_config.num_entries as i64,
+            idle_timeout_behavior: IdleTimeoutBehavior::NoTimeout.into(),
+            is_const_table: false,
+            has_initial_entries: false,
+            other_properties: None,
+        }
+    }
+}
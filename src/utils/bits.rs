@@ -0,0 +1,140 @@
+pub fn byte_width(bitwidth: usize) -> usize {
+    (bitwidth + 7) / 8
+}
+
+pub fn fit(value: &[u8], bitwidth: usize) -> Option<Vec<u8>> {
+    let width = byte_width(bitwidth);
+    let first = value.iter().position(|&b| b != 0).unwrap_or(value.len());
+    let significant = &value[first..];
+    if significant.len() > width {
+        return None;
+    }
+    if let Some(&top) = significant.first() {
+        if significant.len() == width && bitwidth % 8 != 0 && (top >> (bitwidth % 8)) != 0 {
+            return None;
+        }
+    }
+    let mut fitted = vec![0u8; width - significant.len()];
+    fitted.extend_from_slice(significant);
+    return Some(fitted);
+}
+
+pub fn prefix_mask(prefix_len: usize, bitwidth: usize) -> Vec<u8> {
+    let width = byte_width(bitwidth);
+    let mut mask = vec![0u8; width];
+    for bit in (bitwidth - prefix_len.min(bitwidth))..bitwidth {
+        mask[width - 1 - bit / 8] |= 1 << (bit % 8);
+    }
+    return mask;
+}
+
+pub fn apply_mask(value: &[u8], mask: &[u8]) -> Vec<u8> {
+    return value.iter().zip(mask.iter()).map(|(v, m)| v & m).collect();
+}
+
+pub fn pad(value: &[u8], width: usize) -> Vec<u8> {
+    let mut padded = vec![0u8; width.saturating_sub(value.len())];
+    padded.extend_from_slice(value);
+    return padded;
+}
+
+pub fn get_bit(value: &[u8], bit: usize) -> bool {
+    if bit / 8 >= value.len() {
+        return false;
+    }
+    return (value[value.len() - 1 - bit / 8] >> (bit % 8)) & 1 == 1;
+}
+
+pub fn set_bits(buffer: &mut [u8], offset: usize, bitwidth: usize, value: &[u8]) {
+    let length = buffer.len();
+    for bit in 0..bitwidth {
+        let index = offset + bit;
+        if index / 8 >= length {
+            break;
+        }
+        if get_bit(value, bit) {
+            buffer[length - 1 - index / 8] |= 1 << (index % 8);
+        } else {
+            buffer[length - 1 - index / 8] &= !(1 << (index % 8));
+        }
+    }
+}
+
+pub fn from_hex_string(value: &str) -> Option<Vec<u8>> {
+    let digits = value.trim_start_matches("0x").trim_start_matches("0X");
+    let digits = if digits.len() % 2 == 1 { format!("0{}", digits) } else { digits.to_string() };
+    let mut bytes = Vec::with_capacity(digits.len() / 2);
+    for index in (0..digits.len()).step_by(2) {
+        bytes.push(u8::from_str_radix(&digits[index..index + 2], 16).ok()?);
+    }
+    return Some(bytes);
+}
+
+pub fn get_bits(buffer: &[u8], offset: usize, bitwidth: usize) -> Vec<u8> {
+    let mut value = vec![0u8; byte_width(bitwidth)];
+    for bit in 0..bitwidth {
+        if get_bit(buffer, offset + bit) {
+            let length = value.len();
+            value[length - 1 - bit / 8] |= 1 << (bit % 8);
+        }
+    }
+    return value;
+}
+
+pub fn canonical(value: &[u8]) -> Vec<u8> {
+    let first = value.iter().position(|&b| b != 0).unwrap_or(value.len().saturating_sub(1));
+    return value[first..].to_vec();
+}
+
+pub fn count_ones(value: &[u8]) -> usize {
+    return value.iter().map(|b| b.count_ones() as usize).sum();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::canonical;
+    use super::fit;
+    use super::get_bits;
+    use super::prefix_mask;
+    use super::set_bits;
+
+    #[test]
+    fn fit_pads_and_strips_to_bitwidth() {
+        assert_eq!(fit(&[0, 0, 1], 8), Some(vec![1]));
+        assert_eq!(fit(&[1], 16), Some(vec![0, 1]));
+        assert_eq!(fit(&[], 12), Some(vec![0, 0]));
+        assert_eq!(fit(&[0x0f], 4), Some(vec![0x0f]));
+        assert_eq!(fit(&[0x1f], 4), None);
+        assert_eq!(fit(&[1, 0], 8), None);
+    }
+
+    #[test]
+    fn prefix_mask_sets_leading_bits() {
+        assert_eq!(prefix_mask(0, 8), vec![0x00]);
+        assert_eq!(prefix_mask(4, 12), vec![0x0f, 0x00]);
+        assert_eq!(prefix_mask(9, 16), vec![0xff, 0x80]);
+        assert_eq!(prefix_mask(24, 24), vec![0xff, 0xff, 0xff]);
+        assert_eq!(prefix_mask(32, 24), vec![0xff, 0xff, 0xff]);
+    }
+
+    #[test]
+    fn set_bits_and_get_bits_round_trip() {
+        let mut buffer = vec![0xff, 0xff];
+        set_bits(&mut buffer, 4, 8, &[0x00]);
+        assert_eq!(buffer, vec![0xf0, 0x0f]);
+
+        set_bits(&mut buffer, 4, 8, &[0xab]);
+        assert_eq!(buffer, vec![0xfa, 0xbf]);
+        assert_eq!(get_bits(&buffer, 4, 8), vec![0xab]);
+        assert_eq!(get_bits(&buffer, 4, 4), vec![0x0b]);
+        assert_eq!(get_bits(&buffer, 12, 8), vec![0x0f]);
+    }
+
+    #[test]
+    fn canonical_strips_leading_zeros() {
+        assert_eq!(canonical(&[0, 0, 5]), vec![5]);
+        assert_eq!(canonical(&[1, 0]), vec![1, 0]);
+        assert_eq!(canonical(&[0, 0]), vec![0]);
+        assert_eq!(canonical(&[]), Vec::<u8>::new());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::target::driver::XilVitisNetP4ReturnType;
 use crate::target::driver::XilVitisNetP4ReturnTypeToString;
+use p4runtime::google::rpc::Code;
 use std::ffi::CStr;
 
 pub fn code_to_name(code: XilVitisNetP4ReturnType) -> String {
@@ -11,3 +12,19 @@ pub fn code_to_name(code: XilVitisNetP4ReturnType) -> String {
 
     unsafe { CStr::from_ptr(c_str_ptr).to_str().map(|s| s.to_string()).unwrap_or_else(|_| "Unknown Code".to_string()) }
 }
+
+pub fn name_to_rpc_code(name: &str) -> Code {
+    if name.contains("NOT_FOUND") || name.contains("NO_MATCH") {
+        return Code::NotFound;
+    }
+    if name.contains("DUPLICATE") || name.contains("EXISTS") {
+        return Code::AlreadyExists;
+    }
+    if name.contains("FULL") || name.contains("MALLOC") || name.contains("NO_SPACE") {
+        return Code::ResourceExhausted;
+    }
+    if name.contains("INVALID") || name.contains("WRONG") || name.contains("NULL_PARAM") {
+        return Code::InvalidArgument;
+    }
+    return Code::Internal;
+}
@@ -1,4 +1,8 @@
+use p4runtime::google::rpc::Code;
+use p4runtime::p4::config::v1::p4_ids::Prefix;
+use p4runtime::p4::v1::Entity;
 use p4runtime::p4::v1::Uint128;
+use p4runtime::p4::v1::entity::Entity as EntityType;
 
 pub mod get_forwarding_pipeline_config_request {
     use p4runtime::p4::v1::get_forwarding_pipeline_config_request::ResponseType;
@@ -23,3 +27,30 @@ pub fn as_uint128_from(value: u128) -> Uint128 {
 pub fn as_u128_from(value: &Uint128) -> u128 {
     ((value.high as u128) << 64) | (value.low as u128)
 }
+
+pub fn as_tonic_code(code: Code) -> tonic::Code {
+    tonic::Code::from(code as i32)
+}
+
+/// Places `id` in the P4Info id space of `prefix`; applying it twice yields the same id.
+pub fn as_p4_id(prefix: Prefix, id: u32) -> u32 {
+    ((prefix as u32) << 24) | (id & 0xffffff)
+}
+
+/// Returns the P4Info id an entity belongs to; direct resources resolve to their table.
+pub fn as_p4_id_from(entity: &Entity) -> Option<u32> {
+    match entity.entity.as_ref()? {
+        EntityType::ExternEntry(entry) => Some(entry.extern_id),
+        EntityType::TableEntry(entry) => Some(entry.table_id),
+        EntityType::ActionProfileMember(entry) => Some(entry.action_profile_id),
+        EntityType::ActionProfileGroup(entry) => Some(entry.action_profile_id),
+        EntityType::MeterEntry(entry) => Some(entry.meter_id),
+        EntityType::DirectMeterEntry(entry) => entry.table_entry.as_ref().map(|table_entry| table_entry.table_id),
+        EntityType::CounterEntry(entry) => Some(entry.counter_id),
+        EntityType::DirectCounterEntry(entry) => entry.table_entry.as_ref().map(|table_entry| table_entry.table_id),
+        EntityType::PacketReplicationEngineEntry(_) => None,
+        EntityType::ValueSetEntry(entry) => Some(entry.value_set_id),
+        EntityType::RegisterEntry(entry) => Some(entry.register_id),
+        EntityType::DigestEntry(entry) => Some(entry.digest_id),
+    }
+}
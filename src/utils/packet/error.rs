@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PacketError {
+    #[error("Invalid interface name '{name}'")]
+    InvalidName { name: String },
+    #[error("Interface '{name}' not found")]
+    NotFoundInterface { name: String },
+    #[error("Failed to open socket on '{name}'")]
+    Socket {
+        name: String,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Metadata '{name}' does not fit in {bitwidth} bits")]
+    InvalidMetadata { name: String, bitwidth: usize },
+    #[error("Metadata {id} not found")]
+    NotFoundMetadata { id: u32 },
+    #[error("Failed to bind socket to '{name}'")]
+    Bind {
+        name: String,
+        #[source]
+        source: std::io::Error,
+    },
+}
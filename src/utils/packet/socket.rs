@@ -0,0 +1,99 @@
+use super::error::PacketError;
+use libc::AF_PACKET;
+use libc::ETH_P_ALL;
+use libc::PACKET_OUTGOING;
+use libc::SOCK_CLOEXEC;
+use libc::SOCK_NONBLOCK;
+use libc::SOCK_RAW;
+use libc::c_void;
+use libc::sockaddr;
+use libc::sockaddr_ll;
+use libc::socklen_t;
+use std::ffi::CString;
+use std::io;
+use std::mem;
+use std::os::fd::AsRawFd;
+use std::os::fd::FromRawFd;
+use std::os::fd::OwnedFd;
+use std::os::fd::RawFd;
+
+#[derive(Debug)]
+pub struct PacketSocket {
+    name: String,
+    fd: OwnedFd,
+}
+
+impl PacketSocket {
+    pub fn open(name: &str) -> Result<Self, PacketError> {
+        let c_name = CString::new(name).map_err(|_| PacketError::InvalidName { name: name.to_string() })?;
+        let ifindex = unsafe { libc::if_nametoindex(c_name.as_ptr()) };
+        if ifindex == 0 {
+            return Err(PacketError::NotFoundInterface { name: name.to_string() });
+        }
+
+        let protocol = (ETH_P_ALL as u16).to_be();
+        let fd = unsafe { libc::socket(AF_PACKET, SOCK_RAW | SOCK_NONBLOCK | SOCK_CLOEXEC, protocol as i32) };
+        if fd < 0 {
+            return Err(PacketError::Socket {
+                name: name.to_string(),
+                source: io::Error::last_os_error(),
+            });
+        }
+        let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+        let mut address: sockaddr_ll = unsafe { mem::zeroed() };
+        address.sll_family = AF_PACKET as u16;
+        address.sll_protocol = protocol;
+        address.sll_ifindex = ifindex as i32;
+        let result = unsafe { libc::bind(fd.as_raw_fd(), &address as *const sockaddr_ll as *const sockaddr, mem::size_of::<sockaddr_ll>() as socklen_t) };
+        if result < 0 {
+            return Err(PacketError::Bind {
+                name: name.to_string(),
+                source: io::Error::last_os_error(),
+            });
+        }
+
+        Ok(Self { name: name.to_string(), fd: fd })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn send(&self, frame: &[u8]) -> io::Result<usize> {
+        let result = unsafe { libc::send(self.fd.as_raw_fd(), frame.as_ptr() as *const c_void, frame.len(), 0) };
+        if result < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(result as usize)
+    }
+
+    /// Returns `None` for frames this host transmitted on the interface.
+    pub fn recv(&self, buffer: &mut [u8]) -> io::Result<Option<usize>> {
+        let mut address: sockaddr_ll = unsafe { mem::zeroed() };
+        let mut address_len = mem::size_of::<sockaddr_ll>() as socklen_t;
+        let result = unsafe {
+            libc::recvfrom(
+                self.fd.as_raw_fd(),
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer.len(),
+                0,
+                &mut address as *mut sockaddr_ll as *mut sockaddr,
+                &mut address_len as *mut socklen_t,
+            )
+        };
+        if result < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if address.sll_pkttype == PACKET_OUTGOING as u8 {
+            return Ok(None);
+        }
+        Ok(Some(result as usize))
+    }
+}
+
+impl AsRawFd for PacketSocket {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+}